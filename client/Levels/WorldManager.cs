@@ -70,21 +70,27 @@ public partial class WorldManager : Node3D
             }
         };
 
-        _stdb.OnSpitterAoeInserted += (spitterAoe) =>
+        _stdb.OnZoneInserted += (zone) =>
         {
+            // Only the spitter acid has a scene for now
+            if (zone.Kind is not SpacetimeDB.Types.ZoneKind.SpitterAcid)
+            {
+                return;
+            }
+
             var aoeNode = _spitterAoeScene.Instantiate<MeshInstance3D>();
-            aoeNode.Name = "SpitterAoe_" + spitterAoe.Id;
-            aoeNode.GlobalPosition = new Vector3(spitterAoe.Position.X, spitterAoe.Position.Y, spitterAoe.Position.Z);
+            aoeNode.Name = "SpitterAoe_" + zone.Id;
+            aoeNode.GlobalPosition = new Vector3(zone.Position.X, zone.Position.Y, zone.Position.Z);
             AddChild(aoeNode);
-            spitterAoes[spitterAoe.Id] = aoeNode;
+            spitterAoes[zone.Id] = aoeNode;
         };
 
-        _stdb.OnSpitterAoeDeleted += (spitterAoe) =>
+        _stdb.OnZoneDeleted += (zone) =>
         {
-            if (spitterAoes.TryGetValue(spitterAoe.Id, out var node))
+            if (spitterAoes.TryGetValue(zone.Id, out var node))
             {
                 node.QueueFree();
-                spitterAoes.Remove(spitterAoe.Id);
+                spitterAoes.Remove(zone.Id);
             }
         };
 
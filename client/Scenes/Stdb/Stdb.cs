@@ -26,11 +26,11 @@ public partial class Stdb : Node
     public delegate void OnSpitterZombieDeletedDelegate(SpacetimeDB.Types.SpitterZombie zombie);
     public event OnSpitterZombieDeletedDelegate OnSpitterZombieDeleted;
 
-    public delegate void OnSpitterAoeInsertedDelegate(SpacetimeDB.Types.SpitterAoE aoe);
-    public event OnSpitterAoeInsertedDelegate OnSpitterAoeInserted;
+    public delegate void OnZoneInsertedDelegate(SpacetimeDB.Types.Zone zone);
+    public event OnZoneInsertedDelegate OnZoneInserted;
 
-    public delegate void OnSpitterAoeDeletedDelegate(SpacetimeDB.Types.SpitterAoE aoe);
-    public event OnSpitterAoeDeletedDelegate OnSpitterAoeDeleted;
+    public delegate void OnZoneDeletedDelegate(SpacetimeDB.Types.Zone zone);
+    public event OnZoneDeletedDelegate OnZoneDeleted;
 
     public override void _Ready()
     {
@@ -97,14 +97,14 @@ public partial class Stdb : Node
             OnSpitterZombieDeleted?.Invoke(spitterZombie);
         };
 
-        _connection.Db.SpitterAoe.OnInsert += (ctx, spitterAoe) =>
+        _connection.Db.Zone.OnInsert += (ctx, zone) =>
         {
-            OnSpitterAoeInserted?.Invoke(spitterAoe);
+            OnZoneInserted?.Invoke(zone);
         };
 
-        _connection.Db.SpitterAoe.OnDelete += (ctx, spitterAoe) =>
+        _connection.Db.Zone.OnDelete += (ctx, zone) =>
         {
-            OnSpitterAoeDeleted?.Invoke(spitterAoe);
+            OnZoneDeleted?.Invoke(zone);
         };
     }
 
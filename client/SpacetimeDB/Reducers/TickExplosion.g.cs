@@ -12,17 +12,17 @@ namespace SpacetimeDB.Types
 {
     public sealed partial class RemoteReducers : RemoteBase
     {
-        public delegate void TickSpitterAoeHandler(ReducerEventContext ctx, SpitterAoE tick);
-        public event TickSpitterAoeHandler? OnTickSpitterAoe;
+        public delegate void TickExplosionHandler(ReducerEventContext ctx, Explosion tick);
+        public event TickExplosionHandler? OnTickExplosion;
 
-        public void TickSpitterAoe(SpitterAoE tick)
+        public void TickExplosion(Explosion tick)
         {
-            conn.InternalCallReducer(new Reducer.TickSpitterAoe(tick), this.SetCallReducerFlags.TickSpitterAoeFlags);
+            conn.InternalCallReducer(new Reducer.TickExplosion(tick), this.SetCallReducerFlags.TickExplosionFlags);
         }
 
-        public bool InvokeTickSpitterAoe(ReducerEventContext ctx, Reducer.TickSpitterAoe args)
+        public bool InvokeTickExplosion(ReducerEventContext ctx, Reducer.TickExplosion args)
         {
-            if (OnTickSpitterAoe == null)
+            if (OnTickExplosion == null)
             {
                 if (InternalOnUnhandledReducerError != null)
                 {
@@ -34,7 +34,7 @@ namespace SpacetimeDB.Types
                 }
                 return false;
             }
-            OnTickSpitterAoe(
+            OnTickExplosion(
                 ctx,
                 args.Tick
             );
@@ -46,28 +46,28 @@ namespace SpacetimeDB.Types
     {
         [SpacetimeDB.Type]
         [DataContract]
-        public sealed partial class TickSpitterAoe : Reducer, IReducerArgs
+        public sealed partial class TickExplosion : Reducer, IReducerArgs
         {
             [DataMember(Name = "tick")]
-            public SpitterAoE Tick;
+            public Explosion Tick;
 
-            public TickSpitterAoe(SpitterAoE Tick)
+            public TickExplosion(Explosion Tick)
             {
                 this.Tick = Tick;
             }
 
-            public TickSpitterAoe()
+            public TickExplosion()
             {
                 this.Tick = new();
             }
 
-            string IReducerArgs.ReducerName => "tick_spitter_aoe";
+            string IReducerArgs.ReducerName => "tick_explosion";
         }
     }
 
     public sealed partial class SetReducerFlags
     {
-        internal CallReducerFlags TickSpitterAoeFlags;
-        public void TickSpitterAoe(CallReducerFlags flags) => TickSpitterAoeFlags = flags;
+        internal CallReducerFlags TickExplosionFlags;
+        public void TickExplosion(CallReducerFlags flags) => TickExplosionFlags = flags;
     }
 }
@@ -23,8 +23,32 @@ namespace SpacetimeDB.Types
     {
         public RemoteTables(DbConnection conn)
         {
+            AddTable(ActivePowerUp = new(conn));
+            AddTable(Admin = new(conn));
+            AddTable(ArchetypeBehaviorTree = new(conn));
+            AddTable(Barricade = new(conn));
+            AddTable(BehaviorTrace = new(conn));
+            AddTable(BossHealth = new(conn));
+            AddTable(BossZombie = new(conn));
+            AddTable(BossZombieUpdateTick = new(conn));
+            AddTable(BruteZombie = new(conn));
+            AddTable(BruteZombieUpdateTick = new(conn));
+            AddTable(Door = new(conn));
+            AddTable(EntityRigidBody = new(conn));
+            AddTable(ExploderZombie = new(conn));
+            AddTable(ExploderZombieUpdateTick = new(conn));
+            AddTable(Explosion = new(conn));
+            AddTable(Interactable = new(conn));
+            AddTable(NoiseEvent = new(conn));
             AddTable(Player = new(conn));
-            AddTable(SpitterAoe = new(conn));
+            AddTable(PlayerPerk = new(conn));
+            AddTable(PlayerWeapon = new(conn));
+            AddTable(Round = new(conn));
+            AddTable(RunnerZombie = new(conn));
+            AddTable(RunnerZombieUpdateTick = new(conn));
+            AddTable(ScreamerZombie = new(conn));
+            AddTable(ScreamerZombieUpdateTick = new(conn));
+            AddTable(SpitterProjectile = new(conn));
             AddTable(SpitterZombie = new(conn));
             AddTable(SpitterZombieUpdateTick = new(conn));
             AddTable(StengBehaviorTree = new(conn));
@@ -35,11 +59,16 @@ namespace SpacetimeDB.Types
             AddTable(StengRigidBodies = new(conn));
             AddTable(StengTriggers = new(conn));
             AddTable(StengWorld = new(conn));
+            AddTable(WaveDirectorTick = new(conn));
+            AddTable(WeaponDefinition = new(conn));
             AddTable(WorldTick = new(conn));
             AddTable(Zombie = new(conn));
+            AddTable(ZombiePack = new(conn));
             AddTable(ZombieSpawnPoint = new(conn));
+            AddTable(ZombieSpawnRequest = new(conn));
             AddTable(ZombieSpawnTick = new(conn));
             AddTable(ZombieUpdateTick = new(conn));
+            AddTable(Zone = new(conn));
         }
     }
 
@@ -483,16 +512,37 @@ namespace SpacetimeDB.Types
             var encodedArgs = update.ReducerCall.Args;
             return update.ReducerCall.ReducerName switch
             {
+                "debug_behavior_trace" => BSATNHelpers.Decode<Reducer.DebugBehaviorTrace>(encodedArgs),
+                "editor_import_barricades" => BSATNHelpers.Decode<Reducer.EditorImportBarricades>(encodedArgs),
+                "editor_import_doors" => BSATNHelpers.Decode<Reducer.EditorImportDoors>(encodedArgs),
+                "editor_import_interactables" => BSATNHelpers.Decode<Reducer.EditorImportInteractables>(encodedArgs),
+                "editor_import_spawn_points" => BSATNHelpers.Decode<Reducer.EditorImportSpawnPoints>(encodedArgs),
                 "editor_upload_navmesh" => BSATNHelpers.Decode<Reducer.EditorUploadNavmesh>(encodedArgs),
+                "expire_noise_event" => BSATNHelpers.Decode<Reducer.ExpireNoiseEvent>(encodedArgs),
+                "expire_power_up" => BSATNHelpers.Decode<Reducer.ExpirePowerUp>(encodedArgs),
+                "fire" => BSATNHelpers.Decode<Reducer.Fire>(encodedArgs),
                 "generate_external_navmesh" => BSATNHelpers.Decode<Reducer.GenerateExternalNavmesh>(encodedArgs),
                 "on_disconnect" => BSATNHelpers.Decode<Reducer.OnDisconnect>(encodedArgs),
+                "player_interact" => BSATNHelpers.Decode<Reducer.PlayerInteract>(encodedArgs),
+                "player_open_door" => BSATNHelpers.Decode<Reducer.PlayerOpenDoor>(encodedArgs),
                 "player_ready" => BSATNHelpers.Decode<Reducer.PlayerReady>(encodedArgs),
+                "player_repair_barricade" => BSATNHelpers.Decode<Reducer.PlayerRepairBarricade>(encodedArgs),
                 "player_update_position" => BSATNHelpers.Decode<Reducer.PlayerUpdatePosition>(encodedArgs),
+                "reload" => BSATNHelpers.Decode<Reducer.Reload>(encodedArgs),
                 "spawn_zombies" => BSATNHelpers.Decode<Reducer.SpawnZombies>(encodedArgs),
-                "tick_spitter_aoe" => BSATNHelpers.Decode<Reducer.TickSpitterAoe>(encodedArgs),
+                "switch_weapon" => BSATNHelpers.Decode<Reducer.SwitchWeapon>(encodedArgs),
+                "tick_boss_zombie" => BSATNHelpers.Decode<Reducer.TickBossZombie>(encodedArgs),
+                "tick_brute_zombie" => BSATNHelpers.Decode<Reducer.TickBruteZombie>(encodedArgs),
+                "tick_exploder_zombie" => BSATNHelpers.Decode<Reducer.TickExploderZombie>(encodedArgs),
+                "tick_explosion" => BSATNHelpers.Decode<Reducer.TickExplosion>(encodedArgs),
+                "tick_runner_zombie" => BSATNHelpers.Decode<Reducer.TickRunnerZombie>(encodedArgs),
+                "tick_screamer_zombie" => BSATNHelpers.Decode<Reducer.TickScreamerZombie>(encodedArgs),
+                "tick_spitter_projectile" => BSATNHelpers.Decode<Reducer.TickSpitterProjectile>(encodedArgs),
                 "tick_spitter_zombie" => BSATNHelpers.Decode<Reducer.TickSpitterZombie>(encodedArgs),
+                "tick_wave_director" => BSATNHelpers.Decode<Reducer.TickWaveDirector>(encodedArgs),
                 "tick_world" => BSATNHelpers.Decode<Reducer.TickWorld>(encodedArgs),
                 "tick_zombie" => BSATNHelpers.Decode<Reducer.TickZombie>(encodedArgs),
+                "tick_zone" => BSATNHelpers.Decode<Reducer.TickZone>(encodedArgs),
                 "" => throw new SpacetimeDBEmptyReducerNameException("Reducer name is empty"),
                 var reducer => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
@@ -515,16 +565,37 @@ namespace SpacetimeDB.Types
             var eventContext = (ReducerEventContext)context;
             return reducer switch
             {
+                Reducer.DebugBehaviorTrace args => Reducers.InvokeDebugBehaviorTrace(eventContext, args),
+                Reducer.EditorImportBarricades args => Reducers.InvokeEditorImportBarricades(eventContext, args),
+                Reducer.EditorImportDoors args => Reducers.InvokeEditorImportDoors(eventContext, args),
+                Reducer.EditorImportInteractables args => Reducers.InvokeEditorImportInteractables(eventContext, args),
+                Reducer.EditorImportSpawnPoints args => Reducers.InvokeEditorImportSpawnPoints(eventContext, args),
                 Reducer.EditorUploadNavmesh args => Reducers.InvokeEditorUploadNavmesh(eventContext, args),
+                Reducer.ExpireNoiseEvent args => Reducers.InvokeExpireNoiseEvent(eventContext, args),
+                Reducer.ExpirePowerUp args => Reducers.InvokeExpirePowerUp(eventContext, args),
+                Reducer.Fire args => Reducers.InvokeFire(eventContext, args),
                 Reducer.GenerateExternalNavmesh args => Reducers.InvokeGenerateExternalNavmesh(eventContext, args),
                 Reducer.OnDisconnect args => Reducers.InvokeOnDisconnect(eventContext, args),
+                Reducer.PlayerInteract args => Reducers.InvokePlayerInteract(eventContext, args),
+                Reducer.PlayerOpenDoor args => Reducers.InvokePlayerOpenDoor(eventContext, args),
                 Reducer.PlayerReady args => Reducers.InvokePlayerReady(eventContext, args),
+                Reducer.PlayerRepairBarricade args => Reducers.InvokePlayerRepairBarricade(eventContext, args),
                 Reducer.PlayerUpdatePosition args => Reducers.InvokePlayerUpdatePosition(eventContext, args),
+                Reducer.Reload args => Reducers.InvokeReload(eventContext, args),
                 Reducer.SpawnZombies args => Reducers.InvokeSpawnZombies(eventContext, args),
-                Reducer.TickSpitterAoe args => Reducers.InvokeTickSpitterAoe(eventContext, args),
+                Reducer.SwitchWeapon args => Reducers.InvokeSwitchWeapon(eventContext, args),
+                Reducer.TickBossZombie args => Reducers.InvokeTickBossZombie(eventContext, args),
+                Reducer.TickBruteZombie args => Reducers.InvokeTickBruteZombie(eventContext, args),
+                Reducer.TickExploderZombie args => Reducers.InvokeTickExploderZombie(eventContext, args),
+                Reducer.TickExplosion args => Reducers.InvokeTickExplosion(eventContext, args),
+                Reducer.TickRunnerZombie args => Reducers.InvokeTickRunnerZombie(eventContext, args),
+                Reducer.TickScreamerZombie args => Reducers.InvokeTickScreamerZombie(eventContext, args),
+                Reducer.TickSpitterProjectile args => Reducers.InvokeTickSpitterProjectile(eventContext, args),
                 Reducer.TickSpitterZombie args => Reducers.InvokeTickSpitterZombie(eventContext, args),
+                Reducer.TickWaveDirector args => Reducers.InvokeTickWaveDirector(eventContext, args),
                 Reducer.TickWorld args => Reducers.InvokeTickWorld(eventContext, args),
                 Reducer.TickZombie args => Reducers.InvokeTickZombie(eventContext, args),
+                Reducer.TickZone args => Reducers.InvokeTickZone(eventContext, args),
                 _ => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
         }
@@ -13,27 +13,27 @@ namespace SpacetimeDB.Types
 {
     public sealed partial class RemoteTables
     {
-        public sealed class SpitterAoeHandle : RemoteTableHandle<EventContext, SpitterAoE>
+        public sealed class NoiseEventHandle : RemoteTableHandle<EventContext, NoiseEvent>
         {
-            protected override string RemoteTableName => "spitter_aoe";
+            protected override string RemoteTableName => "noise_event";
 
             public sealed class IdUniqueIndex : UniqueIndexBase<ulong>
             {
-                protected override ulong GetKey(SpitterAoE row) => row.Id;
+                protected override ulong GetKey(NoiseEvent row) => row.Id;
 
-                public IdUniqueIndex(SpitterAoeHandle table) : base(table) { }
+                public IdUniqueIndex(NoiseEventHandle table) : base(table) { }
             }
 
             public readonly IdUniqueIndex Id;
 
-            internal SpitterAoeHandle(DbConnection conn) : base(conn)
+            internal NoiseEventHandle(DbConnection conn) : base(conn)
             {
                 Id = new(this);
             }
 
-            protected override object GetPrimaryKey(SpitterAoE row) => row.Id;
+            protected override object GetPrimaryKey(NoiseEvent row) => row.Id;
         }
 
-        public readonly SpitterAoeHandle SpitterAoe;
+        public readonly NoiseEventHandle NoiseEvent;
     }
 }
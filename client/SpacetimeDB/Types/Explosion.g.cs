@@ -11,7 +11,7 @@ namespace SpacetimeDB.Types
 {
     [SpacetimeDB.Type]
     [DataContract]
-    public sealed partial class SpitterAoE
+    public sealed partial class Explosion
     {
         [DataMember(Name = "id")]
         public ulong Id;
@@ -19,27 +19,27 @@ namespace SpacetimeDB.Types
         public ulong TriggerId;
         [DataMember(Name = "position")]
         public Vec3 Position;
-        [DataMember(Name = "delete_at")]
-        public SpacetimeDB.Timestamp DeleteAt;
+        [DataMember(Name = "created_at")]
+        public SpacetimeDB.Timestamp CreatedAt;
         [DataMember(Name = "scheduled_at")]
         public SpacetimeDB.ScheduleAt ScheduledAt;
 
-        public SpitterAoE(
+        public Explosion(
             ulong Id,
             ulong TriggerId,
             Vec3 Position,
-            SpacetimeDB.Timestamp DeleteAt,
+            SpacetimeDB.Timestamp CreatedAt,
             SpacetimeDB.ScheduleAt ScheduledAt
         )
         {
             this.Id = Id;
             this.TriggerId = TriggerId;
             this.Position = Position;
-            this.DeleteAt = DeleteAt;
+            this.CreatedAt = CreatedAt;
             this.ScheduledAt = ScheduledAt;
         }
 
-        public SpitterAoE()
+        public Explosion()
         {
             this.Position = new();
             this.ScheduledAt = null!;
@@ -23,8 +23,20 @@ namespace SpacetimeDB.Types
         public string Name;
         [DataMember(Name = "position")]
         public Vec3 Position;
+        [DataMember(Name = "velocity")]
+        public Vec3 Velocity;
+        [DataMember(Name = "position_updated_at")]
+        public SpacetimeDB.Timestamp? PositionUpdatedAt;
         [DataMember(Name = "rigid_body_id")]
         public ulong RigidBodyId;
+        [DataMember(Name = "health")]
+        public float Health;
+        [DataMember(Name = "points")]
+        public uint Points;
+        [DataMember(Name = "knockback")]
+        public Vec3 Knockback;
+        [DataMember(Name = "knocked_back_at")]
+        public SpacetimeDB.Timestamp KnockedBackAt;
 
         public Player(
             ulong Id,
@@ -32,7 +44,13 @@ namespace SpacetimeDB.Types
             SpacetimeDB.Timestamp JoinedAt,
             string Name,
             Vec3 Position,
-            ulong RigidBodyId
+            Vec3 Velocity,
+            SpacetimeDB.Timestamp? PositionUpdatedAt,
+            ulong RigidBodyId,
+            float Health,
+            uint Points,
+            Vec3 Knockback,
+            SpacetimeDB.Timestamp KnockedBackAt
         )
         {
             this.Id = Id;
@@ -40,13 +58,21 @@ namespace SpacetimeDB.Types
             this.JoinedAt = JoinedAt;
             this.Name = Name;
             this.Position = Position;
+            this.Velocity = Velocity;
+            this.PositionUpdatedAt = PositionUpdatedAt;
             this.RigidBodyId = RigidBodyId;
+            this.Health = Health;
+            this.Points = Points;
+            this.Knockback = Knockback;
+            this.KnockedBackAt = KnockedBackAt;
         }
 
         public Player()
         {
             this.Name = "";
             this.Position = new();
+            this.Velocity = new();
+            this.Knockback = new();
         }
     }
 }
@@ -19,33 +19,61 @@ namespace SpacetimeDB.Types
         public ulong NavigationAgentId;
         [DataMember(Name = "position")]
         public Vec3 Position;
+        [DataMember(Name = "health")]
+        public float Health;
         [DataMember(Name = "target_player")]
         public ulong? TargetPlayer;
         [DataMember(Name = "is_attacking")]
         public bool IsAttacking;
-        [DataMember(Name = "next_attack_time")]
-        public SpacetimeDB.Timestamp NextAttackTime;
+        [DataMember(Name = "attack")]
+        public MeleeAttack Attack;
+        [DataMember(Name = "pack_id")]
+        public ulong? PackId;
+        [DataMember(Name = "barricade_id")]
+        public ulong? BarricadeId;
+        [DataMember(Name = "blackboard")]
+        public Blackboard Blackboard;
+        [DataMember(Name = "stuck")]
+        public StuckTracker Stuck;
+        [DataMember(Name = "body")]
+        public EnemyBody Body;
 
         public Zombie(
             ulong Id,
             ulong NavigationAgentId,
             Vec3 Position,
+            float Health,
             ulong? TargetPlayer,
             bool IsAttacking,
-            SpacetimeDB.Timestamp NextAttackTime
+            MeleeAttack Attack,
+            ulong? PackId,
+            ulong? BarricadeId,
+            Blackboard Blackboard,
+            StuckTracker Stuck,
+            EnemyBody Body
         )
         {
             this.Id = Id;
             this.NavigationAgentId = NavigationAgentId;
             this.Position = Position;
+            this.Health = Health;
             this.TargetPlayer = TargetPlayer;
             this.IsAttacking = IsAttacking;
-            this.NextAttackTime = NextAttackTime;
+            this.Attack = Attack;
+            this.PackId = PackId;
+            this.BarricadeId = BarricadeId;
+            this.Blackboard = Blackboard;
+            this.Stuck = Stuck;
+            this.Body = Body;
         }
 
         public Zombie()
         {
             this.Position = new();
+            this.Attack = new();
+            this.Blackboard = new();
+            this.Stuck = new();
+            this.Body = new();
         }
     }
 }
@@ -17,14 +17,26 @@ namespace SpacetimeDB.Types
         public ulong Id;
         [DataMember(Name = "position")]
         public Vec3 Position;
+        [DataMember(Name = "is_boss_spawn")]
+        public bool IsBossSpawn;
+        [DataMember(Name = "area_id")]
+        public ulong AreaId;
+        [DataMember(Name = "is_enabled")]
+        public bool IsEnabled;
 
         public ZombieSpawnPoint(
             ulong Id,
-            Vec3 Position
+            Vec3 Position,
+            bool IsBossSpawn,
+            ulong AreaId,
+            bool IsEnabled
         )
         {
             this.Id = Id;
             this.Position = Position;
+            this.IsBossSpawn = IsBossSpawn;
+            this.AreaId = AreaId;
+            this.IsEnabled = IsEnabled;
         }
 
         public ZombieSpawnPoint()
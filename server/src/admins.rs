@@ -0,0 +1,22 @@
+use spacetimedb::{table, Identity, ReducerContext, Table};
+
+/// Identity allowed to call the editor reducers. The module owner is made an admin in `init`.
+#[table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+}
+
+impl Admin {
+    pub fn create(ctx: &ReducerContext, identity: Identity) -> Self {
+        ctx.db.admin().insert(Admin { identity })
+    }
+
+    /// Fails unless the caller of the reducer is an admin.
+    pub fn require(ctx: &ReducerContext) -> Result<(), String> {
+        match ctx.db.admin().identity().find(ctx.sender) {
+            Some(_) => Ok(()),
+            None => Err("Only admins can do this".to_string()),
+        }
+    }
+}
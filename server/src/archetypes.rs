@@ -0,0 +1,64 @@
+use std::any::type_name;
+
+use spacetimedb::{table, ReducerContext, Table};
+
+use crate::behavior_debug::BehaviorTreeLeaf;
+
+pub type ArchetypeId = u8;
+
+/// Behavior tree created for an enemy archetype in `init`.
+/// `action_type` records the action enum the tree was built with so that a tick
+/// can't run a tree against the wrong executor, `leaves` lets the behavior traces
+/// locate the actions they record in the tree.
+#[table(name = archetype_behavior_tree)]
+pub struct ArchetypeBehaviorTree {
+    #[primary_key]
+    pub archetype_id: ArchetypeId,
+    pub behavior_tree_id: u64,
+    pub action_type: String,
+    pub leaves: Vec<BehaviorTreeLeaf>,
+}
+
+impl ArchetypeBehaviorTree {
+    pub fn register<A>(
+        ctx: &ReducerContext,
+        archetype_id: ArchetypeId,
+        behavior_tree_id: u64,
+        leaves: Vec<BehaviorTreeLeaf>,
+    ) -> Self {
+        ctx.db
+            .archetype_behavior_tree()
+            .insert(ArchetypeBehaviorTree {
+                archetype_id,
+                behavior_tree_id,
+                action_type: type_name::<A>().to_string(),
+                leaves,
+            })
+    }
+
+    pub fn find(ctx: &ReducerContext, archetype_id: ArchetypeId) -> Option<Self> {
+        ctx.db
+            .archetype_behavior_tree()
+            .archetype_id()
+            .find(archetype_id)
+    }
+
+    /// Returns the behavior tree id registered for `archetype_id`, checking that
+    /// it was built with the `A` action enum.
+    pub fn find_tree_id<A>(ctx: &ReducerContext, archetype_id: ArchetypeId) -> Result<u64, String> {
+        let tree = Self::find(ctx, archetype_id)
+            .ok_or_else(|| format!("No behavior tree registered for archetype {archetype_id}"))?;
+
+        if tree.action_type != type_name::<A>() {
+            return Err(format!(
+                "Behavior tree {} of archetype {} uses actions {}, expected {}",
+                tree.behavior_tree_id,
+                archetype_id,
+                tree.action_type,
+                type_name::<A>()
+            ));
+        }
+
+        Ok(tree.behavior_tree_id)
+    }
+}
@@ -0,0 +1,134 @@
+use spacetime_engine::math::Vec3;
+use spacetimedb::{reducer, table, ReducerContext, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::{admins::Admin, tables::player::Player};
+
+const BARRICADE_MAX_PLANKS: u32 = 6;
+const PLANK_HEALTH: f32 = 40.0;
+/// Points earned for each plank put back.
+const BARRICADE_REPAIR_POINTS: u32 = 10;
+const BARRICADE_REPAIR_INTERVAL_MICROS: i64 = 1_000_000;
+/// Players must be within this distance to repair.
+const BARRICADE_REPAIR_RADIUS: f32 = 2.5;
+
+/// Window boarded up between a spawn point and the map. Enemies spawned there
+/// have to tear every plank down before they can get in. The planks don't block the
+/// navmesh, enemies only stop at the barricade because their behavior tree tells them to.
+#[table(name = barricade, public)]
+pub struct Barricade {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub spawn_point_id: u64,
+    pub position: Vec3,
+    pub planks: u32,
+    /// Health left on the outermost plank.
+    pub plank_health: f32,
+    pub last_repaired_at: Timestamp,
+}
+
+/// Barricade as placed in the level editor.
+#[derive(SpacetimeType)]
+pub struct ImportedBarricade {
+    pub spawn_point_id: u64,
+    pub position: Vec3,
+}
+
+impl Barricade {
+    pub fn create(ctx: &ReducerContext, spawn_point_id: u64, position: Vec3) -> Self {
+        ctx.db.barricade().insert(Barricade {
+            id: 0,
+            spawn_point_id,
+            position,
+            planks: BARRICADE_MAX_PLANKS,
+            plank_health: PLANK_HEALTH,
+            last_repaired_at: ctx.timestamp,
+        })
+    }
+
+    pub fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
+        ctx.db.barricade().id().find(id)
+    }
+
+    pub fn find_for_spawn_point(ctx: &ReducerContext, spawn_point_id: u64) -> Option<Self> {
+        ctx.db
+            .barricade()
+            .spawn_point_id()
+            .filter(spawn_point_id)
+            .next()
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.planks == 0
+    }
+
+    /// Damages the outermost plank, tearing it down once its health reaches zero.
+    pub fn damage(mut self, ctx: &ReducerContext, amount: f32) -> Self {
+        if self.is_broken() {
+            return self;
+        }
+
+        self.plank_health -= amount;
+        if self.plank_health <= 0.0 {
+            self.planks -= 1;
+            self.plank_health = PLANK_HEALTH;
+        }
+        ctx.db.barricade().id().update(self)
+    }
+
+    /// Puts every plank back, for a new game.
+    pub fn rebuild_all(ctx: &ReducerContext) {
+        for mut barricade in ctx.db.barricade().iter() {
+            barricade.planks = BARRICADE_MAX_PLANKS;
+            barricade.plank_health = PLANK_HEALTH;
+            ctx.db.barricade().id().update(barricade);
+        }
+    }
+}
+
+/// Puts one plank back on the barricade.
+#[reducer]
+fn player_repair_barricade(ctx: &ReducerContext, barricade_id: u64) -> Result<(), String> {
+    let player = Player::find_by_identity(ctx, ctx.sender).ok_or("Player not found")?;
+    let mut barricade = Barricade::find(ctx, barricade_id).ok_or("Barricade not found")?;
+
+    if player.position.distance(&barricade.position) > BARRICADE_REPAIR_RADIUS {
+        return Err("Too far from the barricade".to_string());
+    }
+    if barricade.planks >= BARRICADE_MAX_PLANKS {
+        return Err("Barricade already repaired".to_string());
+    }
+    if ctx.timestamp
+        < barricade.last_repaired_at + TimeDuration::from_micros(BARRICADE_REPAIR_INTERVAL_MICROS)
+    {
+        return Err("Repairing too fast".to_string());
+    }
+
+    barricade.planks += 1;
+    barricade.plank_health = PLANK_HEALTH;
+    barricade.last_repaired_at = ctx.timestamp;
+    ctx.db.barricade().id().update(barricade);
+
+    player.add_points(ctx, BARRICADE_REPAIR_POINTS);
+    Ok(())
+}
+
+/// Replaces every barricade with the ones exported from the level.
+#[reducer]
+fn editor_import_barricades(
+    ctx: &ReducerContext,
+    barricades: Vec<ImportedBarricade>,
+) -> Result<(), String> {
+    Admin::require(ctx)?;
+
+    for barricade in ctx.db.barricade().iter() {
+        ctx.db.barricade().id().delete(barricade.id);
+    }
+
+    log::info!("Importing {} barricades", barricades.len());
+    for barricade in barricades {
+        Barricade::create(ctx, barricade.spawn_point_id, barricade.position);
+    }
+    Ok(())
+}
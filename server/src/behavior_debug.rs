@@ -0,0 +1,236 @@
+use std::fmt::Debug;
+
+use serde::Serialize;
+use serde_json::Value;
+use spacetime_engine::behavior::Status;
+use spacetimedb::{reducer, table, ReducerContext, SpacetimeType, Table, Timestamp};
+
+use crate::{
+    admins::Admin,
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    enemy::enemy_exists,
+};
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceStatus {
+    Success,
+    Failure,
+    Running,
+}
+
+impl From<&Status> for TraceStatus {
+    fn from(status: &Status) -> Self {
+        match status {
+            Status::Success => TraceStatus::Success,
+            Status::Failure => TraceStatus::Failure,
+            Status::Running => TraceStatus::Running,
+        }
+    }
+}
+
+/// Action leaf of a behavior tree, with the Select/Sequence nodes leading to it.
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct BehaviorTreeLeaf {
+    /// e.g. `Select[2] > Sequence[1]`, the index being the position among the parent's children.
+    pub path: String,
+    /// The action, serialized as json.
+    pub action: String,
+}
+
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct BehaviorTraceNode {
+    /// Index of the leaf in the tree, in depth-first order.
+    pub leaf: Option<u32>,
+    pub path: String,
+    pub action: String,
+    pub status: TraceStatus,
+}
+
+/// Actions run by the behavior tree of a single enemy during its last tick.
+/// A row exists only while tracing is enabled for that enemy, see `debug_behavior_trace`.
+#[table(name = behavior_trace, public)]
+pub struct BehaviorTrace {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub entity_id: u64,
+    pub archetype_id: ArchetypeId,
+    pub nodes: Vec<BehaviorTraceNode>,
+    pub updated_at: Timestamp,
+}
+
+impl BehaviorTrace {
+    pub fn find(ctx: &ReducerContext, archetype_id: ArchetypeId, entity_id: u64) -> Option<Self> {
+        ctx.db
+            .behavior_trace()
+            .entity_id()
+            .filter(entity_id)
+            .find(|trace| trace.archetype_id == archetype_id)
+    }
+
+    pub fn delete_for(ctx: &ReducerContext, archetype_id: ArchetypeId, entity_id: u64) {
+        if let Some(trace) = Self::find(ctx, archetype_id, entity_id) {
+            ctx.db.behavior_trace().id().delete(trace.id);
+        }
+    }
+}
+
+/// Lists the action leaves of `tree` in depth-first order, which is the order
+/// the tree runs them in. Composite nodes serialize as `{"Select": [children]}`
+/// and leaves as `{"Action": action}`.
+pub fn tree_leaves(tree: &impl Serialize) -> Vec<BehaviorTreeLeaf> {
+    let tree = serde_json::to_value(tree).expect("Failed to serialize behavior tree");
+    let mut leaves = vec![];
+    collect_leaves(&tree, &mut vec![], &mut leaves);
+    leaves
+}
+
+fn collect_leaves(node: &Value, path: &mut Vec<String>, leaves: &mut Vec<BehaviorTreeLeaf>) {
+    let (kind, value) = match node {
+        Value::Object(fields) if fields.len() == 1 => {
+            let (kind, value) = fields.iter().next().unwrap();
+            (kind.as_str(), value)
+        }
+        _ => ("", node),
+    };
+
+    match value {
+        Value::Array(children) => {
+            for (index, child) in children.iter().enumerate() {
+                path.push(format!("{kind}[{index}]"));
+                collect_leaves(child, path, leaves);
+                path.pop();
+            }
+        }
+        action => leaves.push(BehaviorTreeLeaf {
+            path: path.join(" > "),
+            action: action.to_string(),
+        }),
+    }
+}
+
+/// Finds the leaf that ran `action`. An action can appear in several branches, so
+/// the first match after the previously traced leaf wins since the tree runs its
+/// leaves in order within a tick.
+fn find_leaf(leaves: &[BehaviorTreeLeaf], action: &str, after: Option<u32>) -> Option<usize> {
+    let start = after.map_or(0, |leaf| leaf as usize + 1);
+    leaves
+        .iter()
+        .skip(start)
+        .position(|leaf| leaf.action == action)
+        .map(|index| start + index)
+        .or_else(|| leaves.iter().position(|leaf| leaf.action == action))
+}
+
+/// Records `action` and its resulting `status` in the trace of the given enemy.
+/// The trace is reset on the first action of every tick so it only ever holds
+/// the path taken through the tree by the latest tick.
+pub fn trace_action<A: Debug + Serialize>(
+    ctx: &ReducerContext,
+    archetype_id: ArchetypeId,
+    entity_id: u64,
+    action: &A,
+    status: &Status,
+) {
+    let Some(mut trace) = BehaviorTrace::find(ctx, archetype_id, entity_id) else {
+        return;
+    };
+
+    if trace.updated_at != ctx.timestamp {
+        trace.nodes.clear();
+        trace.updated_at = ctx.timestamp;
+    }
+
+    let leaves = ArchetypeBehaviorTree::find(ctx, archetype_id)
+        .map(|tree| tree.leaves)
+        .unwrap_or_default();
+    let serialized = serde_json::to_value(action)
+        .expect("Failed to serialize action")
+        .to_string();
+    let leaf = find_leaf(
+        &leaves,
+        &serialized,
+        trace.nodes.last().and_then(|node| node.leaf),
+    );
+
+    trace.nodes.push(BehaviorTraceNode {
+        leaf: leaf.map(|leaf| leaf as u32),
+        path: leaf.map_or_else(String::new, |leaf| leaves[leaf].path.clone()),
+        action: format!("{:?}", action),
+        status: status.into(),
+    });
+    ctx.db.behavior_trace().id().update(trace);
+}
+
+#[reducer]
+fn debug_behavior_trace(
+    ctx: &ReducerContext,
+    archetype_id: ArchetypeId,
+    entity_id: u64,
+    enabled: bool,
+) -> Result<(), String> {
+    Admin::require(ctx)?;
+
+    let existing = BehaviorTrace::find(ctx, archetype_id, entity_id);
+
+    match (enabled, existing) {
+        (true, None) => {
+            if !enemy_exists(ctx, archetype_id, entity_id) {
+                return Err("Enemy not found".to_string());
+            }
+            ctx.db.behavior_trace().insert(BehaviorTrace {
+                id: 0,
+                entity_id,
+                archetype_id,
+                nodes: vec![],
+                updated_at: ctx.timestamp,
+            });
+        }
+        (false, Some(trace)) => {
+            ctx.db.behavior_trace().id().delete(trace.id);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn leaves() -> Vec<BehaviorTreeLeaf> {
+        tree_leaves(&json!({"Select": [
+            {"Sequence": [
+                {"Action": "IsStuck"},
+                {"Action": {"Enemy": "Chase"}},
+            ]},
+            {"Action": {"Enemy": "Chase"}},
+        ]}))
+    }
+
+    #[test]
+    fn lists_leaves_with_their_path() {
+        let leaves = leaves();
+
+        assert_eq!(leaves.len(), 3);
+        assert_eq!(leaves[0].path, "Select[0] > Sequence[0]");
+        assert_eq!(leaves[0].action, json!("IsStuck").to_string());
+        assert_eq!(leaves[1].path, "Select[0] > Sequence[1]");
+        assert_eq!(leaves[2].path, "Select[1]");
+        assert_eq!(leaves[2].action, json!({"Enemy": "Chase"}).to_string());
+    }
+
+    #[test]
+    fn finds_the_leaf_after_the_previous_one() {
+        let leaves = leaves();
+        let chase = json!({"Enemy": "Chase"}).to_string();
+
+        assert_eq!(find_leaf(&leaves, &chase, None), Some(1));
+        assert_eq!(find_leaf(&leaves, &chase, Some(1)), Some(2));
+        assert_eq!(find_leaf(&leaves, &chase, Some(2)), Some(1));
+        assert_eq!(find_leaf(&leaves, "\"Unknown\"", None), None);
+    }
+}
@@ -0,0 +1,130 @@
+use spacetime_engine::math::Vec3;
+use spacetimedb::{ReducerContext, SpacetimeType, Timestamp};
+
+pub const ATTACK_COOLDOWN_TIMER: &str = "attack_cooldown";
+
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct BlackboardTimer {
+    pub key: String,
+    pub expires_at: Timestamp,
+}
+
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct BlackboardPosition {
+    pub key: String,
+    pub position: Vec3,
+}
+
+/// Memory of an enemy, read and written by its behavior tree actions.
+/// Timers and positions are keyed by name so new behaviors don't need a schema change.
+#[derive(SpacetimeType, Clone, Debug, Default)]
+pub struct Blackboard {
+    pub last_seen_position: Option<Vec3>,
+    pub last_heard_noise: Option<Vec3>,
+    pub timers: Vec<BlackboardTimer>,
+    pub positions: Vec<BlackboardPosition>,
+}
+
+impl Blackboard {
+    pub fn set_timer(&mut self, key: &str, expires_at: Timestamp) {
+        match self.timers.iter_mut().find(|timer| timer.key == key) {
+            Some(timer) => timer.expires_at = expires_at,
+            None => self.timers.push(BlackboardTimer {
+                key: key.to_string(),
+                expires_at,
+            }),
+        }
+    }
+
+    pub fn clear_timer(&mut self, key: &str) {
+        self.timers.retain(|timer| timer.key != key);
+    }
+
+    /// A timer that was never set counts as elapsed.
+    pub fn timer_elapsed(&self, ctx: &ReducerContext, key: &str) -> bool {
+        self.timer_elapsed_at(ctx.timestamp, key)
+    }
+
+    fn timer_elapsed_at(&self, now: Timestamp, key: &str) -> bool {
+        self.timers
+            .iter()
+            .find(|timer| timer.key == key)
+            .is_none_or(|timer| now > timer.expires_at)
+    }
+
+    pub fn set_position(&mut self, key: &str, position: Vec3) {
+        match self.positions.iter_mut().find(|entry| entry.key == key) {
+            Some(entry) => entry.position = position,
+            None => self.positions.push(BlackboardPosition {
+                key: key.to_string(),
+                position,
+            }),
+        }
+    }
+
+    pub fn clear_position(&mut self, key: &str) {
+        self.positions.retain(|entry| entry.key != key);
+    }
+
+    pub fn position(&self, key: &str) -> Option<Vec3> {
+        self.positions
+            .iter()
+            .find(|entry| entry.key == key)
+            .map(|entry| entry.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spacetimedb::TimeDuration;
+
+    use super::*;
+
+    const TIMER: &str = "test_timer";
+
+    fn at(micros: i64) -> Timestamp {
+        Timestamp::UNIX_EPOCH + TimeDuration::from_micros(micros)
+    }
+
+    #[test]
+    fn unset_timer_is_elapsed() {
+        assert!(Blackboard::default().timer_elapsed_at(at(0), TIMER));
+    }
+
+    #[test]
+    fn timer_elapses_after_it_expires() {
+        let mut blackboard = Blackboard::default();
+        blackboard.set_timer(TIMER, at(1_000));
+
+        assert!(!blackboard.timer_elapsed_at(at(500), TIMER));
+        assert!(!blackboard.timer_elapsed_at(at(1_000), TIMER));
+        assert!(blackboard.timer_elapsed_at(at(1_001), TIMER));
+    }
+
+    #[test]
+    fn set_timer_replaces_the_expiry() {
+        let mut blackboard = Blackboard::default();
+        blackboard.set_timer(TIMER, at(1_000));
+        blackboard.set_timer(TIMER, at(5_000));
+
+        assert_eq!(blackboard.timers.len(), 1);
+        assert!(!blackboard.timer_elapsed_at(at(2_000), TIMER));
+    }
+
+    #[test]
+    fn cleared_timer_is_elapsed() {
+        let mut blackboard = Blackboard::default();
+        blackboard.set_timer(TIMER, at(1_000));
+        blackboard.clear_timer(TIMER);
+
+        assert!(blackboard.timer_elapsed_at(at(0), TIMER));
+    }
+
+    #[test]
+    fn timers_are_keyed() {
+        let mut blackboard = Blackboard::default();
+        blackboard.set_timer(TIMER, at(1_000));
+
+        assert!(blackboard.timer_elapsed_at(at(0), ATTACK_COOLDOWN_TIMER));
+    }
+}
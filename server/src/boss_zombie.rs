@@ -0,0 +1,426 @@
+use serde::{Deserialize, Serialize};
+use spacetime_engine::{
+    behavior::{Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
+    math::Vec3,
+    navigation::{DestinationReachedCondition, NavigationAgent, NavigationAgentId},
+    utils::{Entity, WorldEntity},
+    world::World,
+};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration};
+
+use crate::{
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    barricades::Barricade,
+    behavior_debug::{trace_action, tree_leaves},
+    blackboard::Blackboard,
+    constants::{BOSS_ZOMBIE_ARCHETYPE_ID, BOSS_ZOMBIE_COLLIDER_ID},
+    enemy::{impl_enemy_entity, tick_enemies, Enemy},
+    enemy_actions::EnemyAction,
+    enemy_body::{BodyCapsule, EnemyBody},
+    math::point_at_angle,
+    melee::{MeleeAction, MeleeAttack, MeleeAttackProfile, MeleeEnemy},
+    spitter_projectile::SpitterProjectile,
+    stuck::StuckTracker,
+    tables::player::Player,
+    zombies_spawner::{ZombieSpawnPoint, ZombieSpawnRequest},
+    zones::{Zone, ZoneKind},
+};
+
+const BOSS_ZOMBIE_HEALTH: f32 = 3000.0;
+const BOSS_ZOMBIE_KILL_POINTS: u32 = 1000;
+const BOSS_ZOMBIE_HEAD_HEIGHT: f32 = 3.0;
+
+const BOSS_ZOMBIE_SLAM: MeleeAttackProfile = MeleeAttackProfile {
+    range: 2.5,
+    damage: 50.0,
+    wind_up_micros: 1_000_000,
+    strike_micros: 300_000,
+    recovery_micros: 700_000,
+    cooldown_micros: 800_000,
+};
+
+/// Players within this distance are targeted by the spit volley.
+const BOSS_VOLLEY_RANGE: f32 = 20.0;
+const BOSS_VOLLEY_COOLDOWN_MICROS: i64 = 6_000_000;
+/// Number of spitter AoEs created in a ring around the boss by the ground pound.
+const BOSS_POUND_AOE_COUNT: u32 = 6;
+const BOSS_POUND_AOE_DISTANCE: f32 = 5.0;
+const BOSS_POUND_COOLDOWN_MICROS: i64 = 10_000_000;
+/// The boss roars in place for this long when entering a new phase.
+const BOSS_PHASE_TRANSITION_MICROS: i64 = 2_500_000;
+const BOSS_ENRAGED_REINFORCEMENTS: u32 = 6;
+
+const VOLLEY_COOLDOWN_TIMER: &str = "volley_cooldown";
+const POUND_COOLDOWN_TIMER: &str = "pound_cooldown";
+const PHASE_TRANSITION_TIMER: &str = "phase_transition";
+
+pub type BossZombieId = u64;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BossPhase {
+    /// Melee only.
+    First,
+    /// Adds spit volleys at every nearby player.
+    Second,
+    /// Adds a ground pound and calls in reinforcements.
+    Enraged,
+}
+
+impl BossPhase {
+    fn for_health(health: f32) -> Self {
+        let ratio = health / BOSS_ZOMBIE_HEALTH;
+        if ratio > 0.66 {
+            BossPhase::First
+        } else if ratio > 0.33 {
+            BossPhase::Second
+        } else {
+            BossPhase::Enraged
+        }
+    }
+}
+
+#[table(name = boss_zombie, public)]
+#[derive(Clone)]
+pub struct BossZombie {
+    #[primary_key]
+    #[auto_inc]
+    pub id: BossZombieId,
+    #[index(btree)]
+    pub navigation_agent_id: NavigationAgentId,
+    pub position: Vec3,
+    pub health: f32,
+    pub phase: BossPhase,
+    pub target_player: Option<u64>,
+    pub slam: MeleeAttack,
+    pub barricade_id: Option<u64>,
+    pub blackboard: Blackboard,
+    pub stuck: StuckTracker,
+    pub body: EnemyBody,
+}
+
+/// Health of the bosses alive, displayed by the client HUD.
+#[table(name = boss_health, public)]
+pub struct BossHealth {
+    #[primary_key]
+    pub boss_id: BossZombieId,
+    pub health: f32,
+    pub max_health: f32,
+    pub phase: BossPhase,
+}
+
+impl BossHealth {
+    /// Writes the health row of `boss`, if its health or phase changed.
+    fn sync(ctx: &ReducerContext, boss: &BossZombie) {
+        let row = BossHealth {
+            boss_id: boss.id,
+            health: boss.health,
+            max_health: BOSS_ZOMBIE_HEALTH,
+            phase: boss.phase,
+        };
+        match ctx.db.boss_health().boss_id().find(boss.id) {
+            // The boss row is updated on every tick, only write what the HUD didn't show yet
+            Some(current) if current.health == row.health && current.phase == row.phase => {}
+            Some(_) => {
+                ctx.db.boss_health().boss_id().update(row);
+            }
+            None => {
+                ctx.db.boss_health().insert(row);
+            }
+        }
+    }
+}
+
+impl_enemy_entity!(BossZombie, boss_zombie);
+
+impl Enemy for BossZombie {
+    const ARCHETYPE_ID: ArchetypeId = BOSS_ZOMBIE_ARCHETYPE_ID;
+    const BODY: BodyCapsule = BodyCapsule {
+        radius: 1.5,
+        height: 3.4,
+    };
+
+    fn kill_points(&self) -> u32 {
+        BOSS_ZOMBIE_KILL_POINTS
+    }
+
+    fn set_target(&mut self, player_id: u64) {
+        self.target_player = Some(player_id);
+    }
+
+    fn clear_target(&mut self) {
+        self.target_player = None;
+        self.slam.cancel();
+    }
+
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId {
+        NavigationAgent::builder()
+            .desired_speed(2.5)
+            .max_speed(3.5)
+            .radius(1.0)
+            .target_reached_condition(DestinationReachedCondition::Distance(Some(2.5)))
+            .position(position)
+            .build()
+            .insert(ctx)
+            .id()
+    }
+
+    /// The boss is sent back to its own spawn point rather than the nearest one.
+    fn recovery_spawn_point(&self, ctx: &ReducerContext) -> Option<ZombieSpawnPoint> {
+        ZombieSpawnPoint::boss_spawn_point(ctx)
+    }
+
+    /// Bosses are not pushed around.
+    fn knock_back(&mut self, _ctx: &ReducerContext, _position: Vec3) {}
+
+    fn on_saved(&self, ctx: &ReducerContext) {
+        BossHealth::sync(ctx, self);
+    }
+
+    fn on_deleted(&self, ctx: &ReducerContext) {
+        ctx.db.boss_health().boss_id().delete(self.id);
+    }
+}
+
+impl MeleeEnemy for BossZombie {
+    const MELEE_ATTACK: MeleeAttackProfile = BOSS_ZOMBIE_SLAM;
+
+    fn melee_attack_mut(&mut self) -> &mut MeleeAttack {
+        &mut self.slam
+    }
+}
+
+impl BossZombie {
+    pub fn create(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint) -> Self {
+        let position = spawn_point.position;
+        let boss = BossZombie {
+            id: 0,
+            navigation_agent_id: Self::create_navigation_agent(ctx, position),
+            position,
+            health: BOSS_ZOMBIE_HEALTH,
+            phase: BossPhase::First,
+            target_player: None,
+            slam: MeleeAttack::new(ctx),
+            barricade_id: Barricade::find_for_spawn_point(ctx, spawn_point.id)
+                .map(|barricade| barricade.id),
+            blackboard: Blackboard::default(),
+            stuck: StuckTracker::new(position),
+            body: EnemyBody::create(
+                ctx,
+                BOSS_ZOMBIE_COLLIDER_ID,
+                BOSS_ZOMBIE_HEAD_HEIGHT,
+                position,
+            ),
+        }
+        .insert(ctx);
+        boss.body.register(ctx, BOSS_ZOMBIE_ARCHETYPE_ID, boss.id);
+        boss
+    }
+}
+
+#[table(name = boss_zombie_update_tick, scheduled(tick_boss_zombie))]
+pub struct BossZombieUpdateTick {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl BossZombieUpdateTick {
+    pub fn schedule(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .boss_zombie_update_tick()
+            .insert(BossZombieUpdateTick {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(250_000)),
+            })
+    }
+}
+
+#[reducer]
+fn tick_boss_zombie(ctx: &ReducerContext, tick: BossZombieUpdateTick) -> Result<(), String> {
+    tick_enemies::<BossZombie, BossZombieAction>(ctx, tick.scheduled_at)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum BossZombieAction {
+    ShouldChangePhase,
+    ChangePhase,
+    IsChangingPhase,
+    CanGroundPound,
+    GroundPound,
+    CanSpitVolley,
+    SpitVolley,
+    Enemy(EnemyAction),
+    Melee(MeleeAction),
+}
+
+impl BehaviorExecutor<BossZombieAction> for BossZombie {
+    fn run_action(
+        &mut self,
+        ctx: &ReducerContext,
+        _world: &World,
+        _dt: f32,
+        action: &BossZombieAction,
+    ) -> Status {
+        let status = self.execute_action(ctx, action);
+        trace_action(ctx, BOSS_ZOMBIE_ARCHETYPE_ID, self.id, action, &status);
+        status
+    }
+}
+
+impl BossZombie {
+    fn execute_action(&mut self, ctx: &ReducerContext, action: &BossZombieAction) -> Status {
+        let mut agent = NavigationAgent::find(ctx, self.navigation_agent_id)
+            .expect("NavigationAgent not found");
+        match action {
+            BossZombieAction::ShouldChangePhase => {
+                match BossPhase::for_health(self.health) > self.phase {
+                    true => Status::Success,
+                    false => Status::Failure,
+                }
+            }
+            BossZombieAction::ChangePhase => {
+                self.phase = BossPhase::for_health(self.health);
+                self.slam.cancel();
+
+                agent.set_paused(true);
+                agent.update(ctx);
+
+                if self.phase == BossPhase::Enraged {
+                    if let Some(spawn_point) = ZombieSpawnPoint::nearest(ctx, self.position) {
+                        ZombieSpawnRequest::create(
+                            ctx,
+                            spawn_point.id,
+                            BOSS_ENRAGED_REINFORCEMENTS,
+                        );
+                    }
+                }
+
+                self.blackboard.set_timer(
+                    PHASE_TRANSITION_TIMER,
+                    ctx.timestamp + TimeDuration::from_micros(BOSS_PHASE_TRANSITION_MICROS),
+                );
+                self.clone().update(ctx);
+                Status::Success
+            }
+            BossZombieAction::IsChangingPhase => {
+                match self.blackboard.timer_elapsed(ctx, PHASE_TRANSITION_TIMER) {
+                    true => Status::Failure,
+                    false => Status::Success,
+                }
+            }
+            BossZombieAction::CanGroundPound => {
+                match self.phase == BossPhase::Enraged
+                    && self.blackboard.timer_elapsed(ctx, POUND_COOLDOWN_TIMER)
+                {
+                    true => Status::Success,
+                    false => Status::Failure,
+                }
+            }
+            BossZombieAction::GroundPound => {
+                let step = std::f32::consts::TAU / BOSS_POUND_AOE_COUNT as f32;
+                for i in 0..BOSS_POUND_AOE_COUNT {
+                    Zone::create(
+                        ctx,
+                        ZoneKind::SpitterAcid,
+                        point_at_angle(self.position, step * i as f32, BOSS_POUND_AOE_DISTANCE),
+                    );
+                }
+
+                self.blackboard.set_timer(
+                    POUND_COOLDOWN_TIMER,
+                    ctx.timestamp + TimeDuration::from_micros(BOSS_POUND_COOLDOWN_MICROS),
+                );
+                self.clone().update(ctx);
+                Status::Success
+            }
+            BossZombieAction::CanSpitVolley => {
+                match self.phase >= BossPhase::Second
+                    && self.blackboard.timer_elapsed(ctx, VOLLEY_COOLDOWN_TIMER)
+                {
+                    true => Status::Success,
+                    false => Status::Failure,
+                }
+            }
+            BossZombieAction::SpitVolley => {
+                let targets: Vec<Player> = Player::iter(ctx)
+                    .filter(|player| player.position.distance(&self.position) <= BOSS_VOLLEY_RANGE)
+                    .collect();
+                if targets.is_empty() {
+                    return Status::Failure;
+                }
+
+                for player in &targets {
+                    SpitterProjectile::launch(ctx, self.position, player, true);
+                }
+
+                self.blackboard.set_timer(
+                    VOLLEY_COOLDOWN_TIMER,
+                    ctx.timestamp + TimeDuration::from_micros(BOSS_VOLLEY_COOLDOWN_MICROS),
+                );
+                self.clone().update(ctx);
+                Status::Success
+            }
+            BossZombieAction::Enemy(action) => action.run(ctx, self, agent),
+            BossZombieAction::Melee(action) => action.run(ctx, self, agent),
+        }
+    }
+}
+
+pub fn create_boss_zombie_behavior_tree(ctx: &ReducerContext) {
+    let bt = Select(vec![
+        // Tear down the barricade of our spawn point before getting in
+        Sequence(vec![
+            Action(BossZombieAction::Enemy(EnemyAction::IsBehindBarricade)),
+            Action(BossZombieAction::Enemy(EnemyAction::AttackObstacle)),
+        ]),
+        // Enter the next phase as soon as the health drops below its threshold
+        Sequence(vec![
+            Action(BossZombieAction::ShouldChangePhase),
+            Action(BossZombieAction::ChangePhase),
+        ]),
+        // Stand still while roaring
+        Action(BossZombieAction::IsChangingPhase),
+        // Finish a slam once it started, even if the target moved away
+        Sequence(vec![
+            Action(BossZombieAction::Melee(MeleeAction::IsAttacking)),
+            Action(BossZombieAction::Melee(MeleeAction::Attack)),
+        ]),
+        // Ranged attacks unlocked by the later phases
+        Sequence(vec![
+            Action(BossZombieAction::CanGroundPound),
+            Action(BossZombieAction::GroundPound),
+        ]),
+        Sequence(vec![
+            Action(BossZombieAction::CanSpitVolley),
+            Action(BossZombieAction::SpitVolley),
+        ]),
+        // If we are stuck, repath, then side-step and finally teleport back to the boss spawn
+        Sequence(vec![
+            Action(BossZombieAction::Enemy(EnemyAction::IsStuck)),
+            Select(vec![
+                Action(BossZombieAction::Enemy(EnemyAction::Repath)),
+                Action(BossZombieAction::Enemy(EnemyAction::SideStep)),
+                Action(BossZombieAction::Enemy(EnemyAction::TeleportToSpawn)),
+            ]),
+        ]),
+        // Let a side-step play out before chasing again
+        Action(BossZombieAction::Enemy(EnemyAction::IsRecovering)),
+        // If we reached the target, slam it
+        Sequence(vec![
+            Action(BossZombieAction::Enemy(EnemyAction::IsTargetReached)),
+            Action(BossZombieAction::Melee(MeleeAction::Attack)),
+        ]),
+        // Otherwise chase the target, or pick one if we have none
+        Action(BossZombieAction::Enemy(EnemyAction::Chase)),
+        Action(BossZombieAction::Enemy(EnemyAction::TargetRandomPlayer)),
+    ]);
+    let leaves = tree_leaves(&bt);
+    let tree_id = BehaviorTree::create(ctx, bt).id;
+    ArchetypeBehaviorTree::register::<BossZombieAction>(
+        ctx,
+        BOSS_ZOMBIE_ARCHETYPE_ID,
+        tree_id,
+        leaves,
+    );
+}
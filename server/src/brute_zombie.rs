@@ -0,0 +1,445 @@
+use serde::{Deserialize, Serialize};
+use spacetime_engine::{
+    behavior::{Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
+    collisions::{Trigger, TriggerId},
+    math::Vec3,
+    navigation::{DestinationReachedCondition, NavigationAgent, NavigationAgentId},
+    utils::{Entity, WorldEntity},
+    world::World,
+};
+use spacetimedb::{
+    reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+};
+
+use crate::{
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    barricades::Barricade,
+    behavior_debug::{trace_action, tree_leaves},
+    blackboard::Blackboard,
+    constants::{BRUTE_CHARGE_COLLIDER_ID, BRUTE_ZOMBIE_ARCHETYPE_ID, BRUTE_ZOMBIE_COLLIDER_ID},
+    enemy::{impl_enemy_entity, tick_enemies, Enemy},
+    enemy_actions::EnemyAction,
+    enemy_body::{BodyCapsule, EnemyBody},
+    math::{angle_to, point_at_angle},
+    melee::{MeleeAction, MeleeAttack, MeleeAttackProfile, MeleeEnemy},
+    stuck::StuckTracker,
+    tables::entity_rigid_body::EntityRigidBody,
+    zombies_spawner::ZombieSpawnPoint,
+};
+
+const BRUTE_ZOMBIE_HEALTH: f32 = 600.0;
+const BRUTE_ZOMBIE_KILL_POINTS: u32 = 250;
+const BRUTE_ZOMBIE_HEAD_HEIGHT: f32 = 2.3;
+
+const BRUTE_ZOMBIE_SLAM: MeleeAttackProfile = MeleeAttackProfile {
+    range: 1.8,
+    damage: 40.0,
+    wind_up_micros: 900_000,
+    strike_micros: 300_000,
+    recovery_micros: 800_000,
+    cooldown_micros: 1_000_000,
+};
+
+/// The brute only charges at targets between these distances.
+const BRUTE_CHARGE_MIN_RANGE: f32 = 5.0;
+const BRUTE_CHARGE_MAX_RANGE: f32 = 15.0;
+/// The charge runs past the target's position by this distance.
+const BRUTE_CHARGE_OVERSHOOT: f32 = 4.0;
+const BRUTE_CHARGE_TELEGRAPH_MICROS: i64 = 1_200_000;
+const BRUTE_CHARGE_MAX_SPRINT_MICROS: i64 = 2_500_000;
+const BRUTE_CHARGE_COOLDOWN_MICROS: i64 = 8_000_000;
+const BRUTE_CHARGE_DAMAGE: f32 = 30.0;
+const BRUTE_CHARGE_KNOCKBACK: f32 = 12.0;
+
+const CHARGE_COOLDOWN_TIMER: &str = "charge_cooldown";
+
+pub type BruteZombieId = u64;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BruteChargePhase {
+    Idle,
+    /// Standing still, announcing the charge to players.
+    Telegraph,
+    /// Running in a straight line, knocking back the players in its way.
+    Sprint,
+}
+
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct BruteCharge {
+    pub phase: BruteChargePhase,
+    pub destination: Vec3,
+    pub sprint_at: Timestamp,
+    pub ends_at: Timestamp,
+    /// Trigger following the brute while sprinting.
+    pub trigger_id: Option<TriggerId>,
+    /// Players already knocked back by this charge.
+    pub hit_players: Vec<u64>,
+}
+
+impl BruteCharge {
+    fn new(ctx: &ReducerContext) -> Self {
+        Self {
+            phase: BruteChargePhase::Idle,
+            destination: Vec3::ZERO,
+            sprint_at: ctx.timestamp,
+            ends_at: ctx.timestamp,
+            trigger_id: None,
+            hit_players: vec![],
+        }
+    }
+}
+
+#[table(name = brute_zombie, public)]
+#[derive(Clone)]
+pub struct BruteZombie {
+    #[primary_key]
+    #[auto_inc]
+    pub id: BruteZombieId,
+    #[index(btree)]
+    pub navigation_agent_id: NavigationAgentId,
+    pub position: Vec3,
+    pub health: f32,
+    pub target_player: Option<u64>,
+    pub slam: MeleeAttack,
+    pub charge: BruteCharge,
+    pub barricade_id: Option<u64>,
+    pub blackboard: Blackboard,
+    pub stuck: StuckTracker,
+    pub body: EnemyBody,
+}
+
+impl_enemy_entity!(BruteZombie, brute_zombie);
+
+impl Enemy for BruteZombie {
+    const ARCHETYPE_ID: ArchetypeId = BRUTE_ZOMBIE_ARCHETYPE_ID;
+    const BODY: BodyCapsule = BodyCapsule {
+        radius: 1.0,
+        height: 2.6,
+    };
+
+    fn kill_points(&self) -> u32 {
+        BRUTE_ZOMBIE_KILL_POINTS
+    }
+
+    fn set_target(&mut self, player_id: u64) {
+        self.target_player = Some(player_id);
+    }
+
+    fn clear_target(&mut self) {
+        self.target_player = None;
+        self.slam.cancel();
+    }
+
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId {
+        Self::create_agent(ctx, position, false)
+    }
+
+    /// Brutes are too heavy to be pushed around.
+    fn knock_back(&mut self, _ctx: &ReducerContext, _position: Vec3) {}
+
+    fn on_moved(&mut self, ctx: &ReducerContext) {
+        self.sync_charge_trigger(ctx);
+    }
+
+    fn on_deleted(&self, ctx: &ReducerContext) {
+        if let Some(trigger) = self.charge.trigger_id.and_then(|id| Trigger::find(ctx, id)) {
+            trigger.delete(ctx);
+        }
+    }
+}
+
+impl MeleeEnemy for BruteZombie {
+    const MELEE_ATTACK: MeleeAttackProfile = BRUTE_ZOMBIE_SLAM;
+
+    fn melee_attack_mut(&mut self) -> &mut MeleeAttack {
+        &mut self.slam
+    }
+}
+
+impl BruteZombie {
+    pub fn create(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint) -> Self {
+        let position = spawn_point.position;
+        let brute = BruteZombie {
+            id: 0,
+            navigation_agent_id: Self::create_navigation_agent(ctx, position),
+            position,
+            health: BRUTE_ZOMBIE_HEALTH,
+            target_player: None,
+            slam: MeleeAttack::new(ctx),
+            charge: BruteCharge::new(ctx),
+            barricade_id: Barricade::find_for_spawn_point(ctx, spawn_point.id)
+                .map(|barricade| barricade.id),
+            blackboard: Blackboard::default(),
+            stuck: StuckTracker::new(position),
+            body: EnemyBody::create(
+                ctx,
+                BRUTE_ZOMBIE_COLLIDER_ID,
+                BRUTE_ZOMBIE_HEAD_HEIGHT,
+                position,
+            ),
+        }
+        .insert(ctx);
+        brute
+            .body
+            .register(ctx, BRUTE_ZOMBIE_ARCHETYPE_ID, brute.id);
+        brute
+    }
+
+    /// The brute swaps its agent for a much faster one while sprinting.
+    fn create_agent(ctx: &ReducerContext, position: Vec3, sprinting: bool) -> NavigationAgentId {
+        let (desired_speed, max_speed) = match sprinting {
+            true => (12.0, 14.0),
+            false => (2.0, 3.0),
+        };
+        NavigationAgent::builder()
+            .desired_speed(desired_speed)
+            .max_speed(max_speed)
+            .radius(0.6)
+            .target_reached_condition(DestinationReachedCondition::Distance(Some(2.0)))
+            .position(position)
+            .build()
+            .insert(ctx)
+            .id()
+    }
+
+    /// Moves the charge trigger along with the brute and knocks back the players inside it.
+    fn sync_charge_trigger(&mut self, ctx: &ReducerContext) {
+        let Some(mut trigger) = self.charge.trigger_id.and_then(|id| Trigger::find(ctx, id)) else {
+            return;
+        };
+
+        for rigid_body_id in &trigger.entities_inside {
+            let Some(player) = EntityRigidBody::find_player(ctx, *rigid_body_id) else {
+                continue;
+            };
+            if self.charge.hit_players.contains(&player.id) {
+                continue;
+            }
+
+            self.charge.hit_players.push(player.id);
+            let impulse = point_at_angle(
+                Vec3::ZERO,
+                angle_to(self.position, player.position),
+                BRUTE_CHARGE_KNOCKBACK,
+            );
+            player
+                .damage(ctx, BRUTE_CHARGE_DAMAGE)
+                .knock_back(ctx, impulse);
+        }
+
+        trigger.position = self.position;
+        trigger.update(ctx);
+    }
+
+    fn swap_navigation_agent(
+        &mut self,
+        ctx: &ReducerContext,
+        agent: NavigationAgent,
+        sprinting: bool,
+    ) {
+        agent.delete(ctx);
+        self.navigation_agent_id = Self::create_agent(ctx, self.position, sprinting);
+    }
+}
+
+#[table(name = brute_zombie_update_tick, scheduled(tick_brute_zombie))]
+pub struct BruteZombieUpdateTick {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl BruteZombieUpdateTick {
+    pub fn schedule(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .brute_zombie_update_tick()
+            .insert(BruteZombieUpdateTick {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(250_000)),
+            })
+    }
+}
+
+#[reducer]
+fn tick_brute_zombie(ctx: &ReducerContext, tick: BruteZombieUpdateTick) -> Result<(), String> {
+    tick_enemies::<BruteZombie, BruteZombieAction>(ctx, tick.scheduled_at)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum BruteZombieAction {
+    IsCharging,
+    CanCharge,
+    Charge,
+    Enemy(EnemyAction),
+    Melee(MeleeAction),
+}
+
+impl BehaviorExecutor<BruteZombieAction> for BruteZombie {
+    fn run_action(
+        &mut self,
+        ctx: &ReducerContext,
+        _world: &World,
+        _dt: f32,
+        action: &BruteZombieAction,
+    ) -> Status {
+        let status = self.execute_action(ctx, action);
+        trace_action(ctx, BRUTE_ZOMBIE_ARCHETYPE_ID, self.id, action, &status);
+        status
+    }
+}
+
+impl BruteZombie {
+    fn execute_action(&mut self, ctx: &ReducerContext, action: &BruteZombieAction) -> Status {
+        let mut agent = NavigationAgent::find(ctx, self.navigation_agent_id)
+            .expect("NavigationAgent not found");
+        match action {
+            BruteZombieAction::IsCharging => match self.charge.phase {
+                BruteChargePhase::Idle => Status::Failure,
+                _ => Status::Success,
+            },
+            BruteZombieAction::CanCharge => {
+                let Some(player) = self.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
+
+                let distance = player.position.distance(&agent.position());
+                match self.blackboard.timer_elapsed(ctx, CHARGE_COOLDOWN_TIMER)
+                    && (BRUTE_CHARGE_MIN_RANGE..=BRUTE_CHARGE_MAX_RANGE).contains(&distance)
+                {
+                    true => Status::Success,
+                    false => Status::Failure,
+                }
+            }
+            BruteZombieAction::Charge => match self.charge.phase {
+                BruteChargePhase::Idle => {
+                    let Some(player) = self.find_target_player(ctx) else {
+                        return Status::Failure;
+                    };
+
+                    // The direction is locked when telegraphing so the charge can be sidestepped
+                    let distance = player.position.distance(&self.position);
+                    self.charge.destination = point_at_angle(
+                        self.position,
+                        angle_to(self.position, player.position),
+                        distance + BRUTE_CHARGE_OVERSHOOT,
+                    );
+                    self.charge.phase = BruteChargePhase::Telegraph;
+                    self.charge.sprint_at =
+                        ctx.timestamp + TimeDuration::from_micros(BRUTE_CHARGE_TELEGRAPH_MICROS);
+                    self.charge.hit_players.clear();
+
+                    agent.set_paused(true);
+                    agent.update(ctx);
+
+                    self.clone().update(ctx);
+                    Status::Success
+                }
+                BruteChargePhase::Telegraph => {
+                    if ctx.timestamp < self.charge.sprint_at {
+                        return Status::Success;
+                    }
+
+                    self.swap_navigation_agent(ctx, agent, true);
+                    let mut sprint_agent = NavigationAgent::find(ctx, self.navigation_agent_id)
+                        .expect("NavigationAgent not found");
+                    sprint_agent.set_destination(Some(self.charge.destination));
+                    sprint_agent.update(ctx);
+
+                    self.charge.trigger_id = Some(
+                        Trigger::builder()
+                            .position(self.position)
+                            .collider_id(BRUTE_CHARGE_COLLIDER_ID)
+                            .build()
+                            .insert(ctx)
+                            .id,
+                    );
+                    self.charge.phase = BruteChargePhase::Sprint;
+                    self.charge.ends_at =
+                        ctx.timestamp + TimeDuration::from_micros(BRUTE_CHARGE_MAX_SPRINT_MICROS);
+                    self.clone().update(ctx);
+                    Status::Success
+                }
+                BruteChargePhase::Sprint => {
+                    if ctx.timestamp < self.charge.ends_at && !agent.has_reached_destination() {
+                        return Status::Success;
+                    }
+
+                    if let Some(trigger) =
+                        self.charge.trigger_id.and_then(|id| Trigger::find(ctx, id))
+                    {
+                        trigger.delete(ctx);
+                    }
+                    self.swap_navigation_agent(ctx, agent, false);
+
+                    self.charge.trigger_id = None;
+                    self.charge.phase = BruteChargePhase::Idle;
+                    self.blackboard.set_timer(
+                        CHARGE_COOLDOWN_TIMER,
+                        ctx.timestamp + TimeDuration::from_micros(BRUTE_CHARGE_COOLDOWN_MICROS),
+                    );
+                    self.clone().update(ctx);
+                    Status::Success
+                }
+            },
+            BruteZombieAction::Enemy(action) => action.run(ctx, self, agent),
+            BruteZombieAction::Melee(action) => action.run(ctx, self, agent),
+        }
+    }
+}
+
+pub fn create_brute_zombie_behavior_tree(ctx: &ReducerContext) {
+    let bt = Select(vec![
+        // Tear down the barricade of our spawn point before getting in
+        Sequence(vec![
+            Action(BruteZombieAction::Enemy(EnemyAction::IsBehindBarricade)),
+            Action(BruteZombieAction::Enemy(EnemyAction::AttackObstacle)),
+        ]),
+        // Finish a charge once it started
+        Sequence(vec![
+            Action(BruteZombieAction::IsCharging),
+            Action(BruteZombieAction::Charge),
+        ]),
+        // Finish a slam once it started, even if the target moved away
+        Sequence(vec![
+            Action(BruteZombieAction::Melee(MeleeAction::IsAttacking)),
+            Action(BruteZombieAction::Melee(MeleeAction::Attack)),
+        ]),
+        // Charge at targets that are neither too close nor too far
+        Sequence(vec![
+            Action(BruteZombieAction::CanCharge),
+            Action(BruteZombieAction::Charge),
+        ]),
+        // If we are stuck, repath, then side-step and finally teleport to the nearest spawn
+        Sequence(vec![
+            Action(BruteZombieAction::Enemy(EnemyAction::IsStuck)),
+            Select(vec![
+                Action(BruteZombieAction::Enemy(EnemyAction::Repath)),
+                Action(BruteZombieAction::Enemy(EnemyAction::SideStep)),
+                Action(BruteZombieAction::Enemy(EnemyAction::TeleportToSpawn)),
+            ]),
+        ]),
+        // Let a side-step play out before chasing again
+        Action(BruteZombieAction::Enemy(EnemyAction::IsRecovering)),
+        // If we are moving, chase the target (i.e update target position)
+        Sequence(vec![
+            Action(BruteZombieAction::Enemy(EnemyAction::IsMoving)),
+            Action(BruteZombieAction::Enemy(EnemyAction::Chase)),
+        ]),
+        // If we reached the target, slam it
+        Sequence(vec![
+            Action(BruteZombieAction::Enemy(EnemyAction::IsTargetReached)),
+            Action(BruteZombieAction::Melee(MeleeAction::Attack)),
+        ]),
+        // Otherwise, target a random player
+        Action(BruteZombieAction::Enemy(EnemyAction::TargetRandomPlayer)),
+    ]);
+    let leaves = tree_leaves(&bt);
+    let tree_id = BehaviorTree::create(ctx, bt).id;
+    ArchetypeBehaviorTree::register::<BruteZombieAction>(
+        ctx,
+        BRUTE_ZOMBIE_ARCHETYPE_ID,
+        tree_id,
+        leaves,
+    );
+}
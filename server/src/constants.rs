@@ -1,6 +1,48 @@
 use spacetime_engine::{collisions::ColliderId, world::WorldId};
 
+use crate::archetypes::ArchetypeId;
+
 pub const WORLD_ID: WorldId = 1;
 pub const PLAYER_COLLIDER_ID: ColliderId = 1;
 pub const SPITTER_AOE_COLLIDER_ID: ColliderId = 2;
+pub const BRUTE_CHARGE_COLLIDER_ID: ColliderId = 3;
+pub const EXPLODER_EXPLOSION_COLLIDER_ID: ColliderId = 4;
+pub const POWER_UP_COLLIDER_ID: ColliderId = 5;
+pub const ZOMBIE_COLLIDER_ID: ColliderId = 6;
+pub const SPITTER_ZOMBIE_COLLIDER_ID: ColliderId = 7;
+pub const RUNNER_ZOMBIE_COLLIDER_ID: ColliderId = 8;
+pub const BRUTE_ZOMBIE_COLLIDER_ID: ColliderId = 9;
+pub const EXPLODER_ZOMBIE_COLLIDER_ID: ColliderId = 10;
+pub const SCREAMER_ZOMBIE_COLLIDER_ID: ColliderId = 11;
+pub const BOSS_ZOMBIE_COLLIDER_ID: ColliderId = 12;
+pub const ENEMY_HEAD_COLLIDER_ID: ColliderId = 13;
 pub const SPITTER_SPAWN_CHANCE: f32 = 0.1;
+pub const RUNNER_SPAWN_CHANCE: f32 = 0.15;
+pub const EXPLODER_SPAWN_CHANCE: f32 = 0.08;
+pub const SCREAMER_SPAWN_CHANCE: f32 = 0.04;
+/// Chance for a regular zombie spawn to be a whole pack instead.
+pub const PACK_SPAWN_CHANCE: f32 = 0.3;
+pub const PACK_MIN_SIZE: u32 = 3;
+pub const PACK_MAX_SIZE: u32 = 5;
+/// The spawner stops once this many enemies of any archetype are alive.
+pub const MAX_ENEMIES_ALIVE: u64 = 100;
+pub const BRUTE_SPAWN_CHANCE: f32 = 0.02;
+/// Brutes are mini-bosses, only this many can be alive at once.
+pub const BRUTE_MAX_ALIVE: u64 = 1;
+pub const PLAYER_MAX_HEALTH: f32 = 100.0;
+pub const PLAYER_STARTING_POINTS: u32 = 500;
+pub const POINTS_PER_HIT: u32 = 10;
+/// Chance for an enemy killed by a player to drop a power-up.
+pub const POWER_UP_DROP_CHANCE: f32 = 0.03;
+/// The wave director spawns a boss every this many rounds.
+pub const BOSS_ROUND_INTERVAL: u32 = 5;
+/// Area players start in, open without buying any door.
+pub const STARTING_AREA_ID: u64 = 0;
+
+pub const ZOMBIE_ARCHETYPE_ID: ArchetypeId = 1;
+pub const SPITTER_ZOMBIE_ARCHETYPE_ID: ArchetypeId = 2;
+pub const RUNNER_ZOMBIE_ARCHETYPE_ID: ArchetypeId = 3;
+pub const BRUTE_ZOMBIE_ARCHETYPE_ID: ArchetypeId = 4;
+pub const EXPLODER_ZOMBIE_ARCHETYPE_ID: ArchetypeId = 5;
+pub const SCREAMER_ZOMBIE_ARCHETYPE_ID: ArchetypeId = 6;
+pub const BOSS_ZOMBIE_ARCHETYPE_ID: ArchetypeId = 7;
@@ -0,0 +1,121 @@
+use spacetime_engine::math::Vec3;
+use spacetimedb::{reducer, table, ReducerContext, SpacetimeType, Table};
+
+use crate::{
+    admins::Admin, constants::WORLD_ID, navmesh::rebuild_navmesh, tables::player::Player,
+    zombies_spawner::ZombieSpawnPoint,
+};
+
+/// Door or barrier players buy to open the area behind it. Closed doors are cut out
+/// of the navmesh, see [`rebuild_navmesh`].
+#[table(name = door, public)]
+pub struct Door {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Area opened when the door is bought.
+    pub area_id: u64,
+    pub position: Vec3,
+    pub cost: u32,
+    /// Players must be within this distance to buy the door.
+    pub radius: f32,
+    /// Navmesh polygons centered within this distance are removed while the door is closed.
+    pub block_radius: f32,
+    pub is_open: bool,
+}
+
+/// Door as placed in the level editor.
+#[derive(SpacetimeType)]
+pub struct ImportedDoor {
+    pub area_id: u64,
+    pub position: Vec3,
+    pub cost: u32,
+    pub radius: f32,
+    pub block_radius: f32,
+}
+
+impl Door {
+    pub fn create(
+        ctx: &ReducerContext,
+        area_id: u64,
+        position: Vec3,
+        cost: u32,
+        radius: f32,
+        block_radius: f32,
+    ) -> Self {
+        ctx.db.door().insert(Door {
+            id: 0,
+            area_id,
+            position,
+            cost,
+            radius,
+            block_radius,
+            is_open: false,
+        })
+    }
+
+    /// Whether the closed door stands in the way of `point`.
+    pub fn blocks(&self, point: Vec3) -> bool {
+        !self.is_open && self.position.distance(&point) <= self.block_radius
+    }
+
+    /// Opens the door, letting enemies path through it, and enables the spawn points of its area.
+    pub fn open(mut self, ctx: &ReducerContext) -> Self {
+        self.is_open = true;
+        ZombieSpawnPoint::set_area_enabled(ctx, self.area_id, true);
+        let door = ctx.db.door().id().update(self);
+        rebuild_navmesh(ctx, WORLD_ID);
+        door
+    }
+
+    /// Closes every door, back to the map players start with.
+    pub fn reset(ctx: &ReducerContext) {
+        for mut door in ctx.db.door().iter().filter(|door| door.is_open) {
+            door.is_open = false;
+            ctx.db.door().id().update(door);
+        }
+        ZombieSpawnPoint::reset_areas(ctx);
+        rebuild_navmesh(ctx, WORLD_ID);
+    }
+}
+
+#[reducer]
+fn player_open_door(ctx: &ReducerContext, door_id: u64) -> Result<(), String> {
+    let player = Player::find_by_identity(ctx, ctx.sender).ok_or("Player not found")?;
+    let door = ctx.db.door().id().find(door_id).ok_or("Door not found")?;
+
+    if door.is_open {
+        return Err("Door already open".to_string());
+    }
+    if player.position.distance(&door.position) > door.radius {
+        return Err("Too far from the door".to_string());
+    }
+
+    player.spend_points(ctx, door.cost)?;
+    door.open(ctx);
+    Ok(())
+}
+
+/// Replaces every door with the ones exported from the level.
+#[reducer]
+fn editor_import_doors(ctx: &ReducerContext, doors: Vec<ImportedDoor>) -> Result<(), String> {
+    Admin::require(ctx)?;
+
+    for door in ctx.db.door().iter() {
+        ctx.db.door().id().delete(door.id);
+    }
+
+    log::info!("Importing {} doors", doors.len());
+    for door in doors {
+        Door::create(
+            ctx,
+            door.area_id,
+            door.position,
+            door.cost,
+            door.radius,
+            door.block_radius,
+        );
+    }
+    rebuild_navmesh(ctx, WORLD_ID);
+    Ok(())
+}
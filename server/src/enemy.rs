@@ -0,0 +1,433 @@
+use std::fmt::Debug;
+
+use serde::{de::DeserializeOwned, Serialize};
+use spacetime_engine::{
+    behavior::{tick_behavior, BehaviorExecutor},
+    math::Vec3,
+    navigation::{NavigationAgent, NavigationAgentId},
+    utils::{get_delta_time, Entity, WorldEntity},
+    world::World,
+};
+use spacetimedb::{rand::seq::IteratorRandom, ReducerContext, ScheduleAt, TimeDuration};
+
+use crate::{
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    blackboard::Blackboard,
+    boss_zombie::BossZombie,
+    brute_zombie::BruteZombie,
+    constants::{
+        BOSS_ZOMBIE_ARCHETYPE_ID, BRUTE_ZOMBIE_ARCHETYPE_ID, EXPLODER_ZOMBIE_ARCHETYPE_ID,
+        POINTS_PER_HIT, RUNNER_ZOMBIE_ARCHETYPE_ID, SCREAMER_ZOMBIE_ARCHETYPE_ID,
+        SPITTER_ZOMBIE_ARCHETYPE_ID, WORLD_ID, ZOMBIE_ARCHETYPE_ID,
+    },
+    enemy_body::{BodyCapsule, EnemyBody},
+    exploder_zombie::ExploderZombie,
+    math::{angle_to, point_at_angle},
+    power_ups::{ActivePowerUp, PowerUpKind},
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
+    spitter_zombie::SpitterZombie,
+    stuck::StuckTracker,
+    tables::{player::Player, zombie::Zombie},
+    zombies_spawner::ZombieSpawnPoint,
+};
+
+/// Time a knocked back enemy spends being pushed before chasing again.
+const KNOCK_BACK_MICROS: i64 = 500_000;
+pub const KNOCK_BACK_TIMER: &str = "knock_back";
+
+/// Columns every enemy table has, implemented by [`impl_enemy_entity`].
+pub trait EnemyFields {
+    fn id(&self) -> u64;
+
+    fn position(&self) -> Vec3;
+
+    fn position_mut(&mut self) -> &mut Vec3;
+
+    fn navigation_agent_id(&self) -> NavigationAgentId;
+
+    fn navigation_agent_id_mut(&mut self) -> &mut NavigationAgentId;
+
+    fn health_mut(&mut self) -> &mut f32;
+
+    fn blackboard_mut(&mut self) -> &mut Blackboard;
+
+    fn stuck_mut(&mut self) -> &mut StuckTracker;
+
+    /// Barricade to tear down before going after players, cleared once through it.
+    fn barricade_id_mut(&mut self) -> &mut Option<u64>;
+
+    fn body(&self) -> &EnemyBody;
+
+    fn target_player(&self) -> Option<u64>;
+}
+
+/// State shared by every enemy archetype driven by a behavior tree.
+pub trait Enemy: Entity + EnemyFields + Clone {
+    const ARCHETYPE_ID: ArchetypeId;
+
+    /// Body collider of the archetype.
+    const BODY: BodyCapsule;
+
+    /// Points awarded to the player landing the killing blow.
+    fn kill_points(&self) -> u32;
+
+    fn set_target(&mut self, player_id: u64);
+
+    /// Forgets the targeted player and stops any attack on it.
+    fn clear_target(&mut self);
+
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId;
+
+    /// Where the enemy heads to when going after `player`.
+    fn chase_destination(&self, _ctx: &ReducerContext, player: &Player) -> Vec3 {
+        player.position
+    }
+
+    /// Player to go after when the enemy has no target.
+    fn choose_target(&self, ctx: &ReducerContext) -> Option<Player> {
+        Player::iter(ctx).choose(&mut ctx.rng())
+    }
+
+    /// Spawn point the enemy is sent back to when it can't get unstuck.
+    fn recovery_spawn_point(&self, ctx: &ReducerContext) -> Option<ZombieSpawnPoint> {
+        ZombieSpawnPoint::nearest(ctx, self.position())
+    }
+
+    /// Returns the targeted player, clearing the target if that player no longer exists.
+    fn find_target_player(&mut self, ctx: &ReducerContext) -> Option<Player> {
+        let target_player = self.target_player()?;
+        let player = Player::find(ctx, target_player);
+        if player.is_none() {
+            self.clear_target();
+            self.clone().update(ctx);
+        }
+        player
+    }
+
+    /// Moves the enemy to `position`, replacing its navigation agent.
+    fn teleport(&mut self, ctx: &ReducerContext, position: Vec3) {
+        if let Some(agent) = NavigationAgent::find(ctx, self.navigation_agent_id()) {
+            agent.delete(ctx);
+        }
+        *self.navigation_agent_id_mut() = Self::create_navigation_agent(ctx, position);
+        *self.position_mut() = position;
+        self.stuck_mut().reset(position);
+    }
+
+    /// Pushes the enemy towards `position`. The agent walks there along the navmesh,
+    /// keeping its speed, and goes back to its target once the push played out.
+    fn knock_back(&mut self, ctx: &ReducerContext, position: Vec3) {
+        let Some(mut agent) = NavigationAgent::find(ctx, self.navigation_agent_id()) else {
+            return;
+        };
+
+        agent.set_destination(Some(position)).set_paused(false);
+        agent.update(ctx);
+
+        // Holds the chase back through the knock back branch of the behavior trees
+        self.blackboard_mut().set_timer(
+            KNOCK_BACK_TIMER,
+            ctx.timestamp + TimeDuration::from_micros(KNOCK_BACK_MICROS),
+        );
+    }
+
+    /// Called on every behavior tick with every enemy of the archetype, before their trees run.
+    fn before_tick(_ctx: &ReducerContext, _enemies: &mut [Self]) {}
+
+    /// Called on every world tick, once the enemy moved along with its agent.
+    fn on_moved(&mut self, _ctx: &ReducerContext) {}
+
+    /// Called right after the enemy's row is inserted or updated.
+    fn on_saved(&self, _ctx: &ReducerContext) {}
+
+    /// Called right after the enemy's row is deleted, to clean up what it owns.
+    fn on_deleted(&self, _ctx: &ReducerContext) {}
+
+    /// Called right before the enemy is deleted because its health reached zero.
+    fn on_death(&self, _ctx: &ReducerContext) {}
+
+    /// Removes `amount` health, deleting the enemy when it reaches zero.
+    /// The `attacker` earns points for the hit and the kill. Returns true if the enemy died.
+    fn damage(mut self, ctx: &ReducerContext, amount: f32, attacker: Option<u64>) -> bool {
+        let insta_kill =
+            attacker.is_some() && ActivePowerUp::is_active(ctx, PowerUpKind::InstaKill);
+        let health = self.health_mut();
+        *health = match insta_kill {
+            true => 0.0,
+            false => (*health - amount).max(0.0),
+        };
+        let killed = *health <= 0.0;
+
+        if let Some(player) = attacker.and_then(|id| Player::find(ctx, id)) {
+            let points = match killed {
+                true => POINTS_PER_HIT + self.kill_points(),
+                false => POINTS_PER_HIT,
+            };
+            player.add_points(ctx, points);
+        }
+
+        if !killed {
+            self.update(ctx);
+            return false;
+        }
+
+        if attacker.is_some() {
+            PowerUpKind::roll_drop(ctx, self.position());
+        }
+        self.on_death(ctx);
+        self.delete(ctx);
+        true
+    }
+}
+
+/// Implements [`Entity`] and [`EnemyFields`] for the table `$table` of the enemy `$enemy`.
+/// Deleting an enemy also deletes its navigation agent, its rigid bodies and its
+/// behavior trace, see [`Enemy::on_deleted`] for anything else it owns.
+macro_rules! impl_enemy_entity {
+    ($enemy:ident, $table:ident) => {
+        impl ::spacetime_engine::utils::Entity for $enemy {
+            fn insert(self, ctx: &::spacetimedb::ReducerContext) -> Self {
+                use ::spacetimedb::Table;
+                let enemy = ctx.db.$table().insert(self);
+                $crate::enemy::Enemy::on_saved(&enemy, ctx);
+                enemy
+            }
+
+            fn find(ctx: &::spacetimedb::ReducerContext, id: u64) -> Option<Self> {
+                ctx.db.$table().id().find(id)
+            }
+
+            fn iter(ctx: &::spacetimedb::ReducerContext) -> impl Iterator<Item = Self> {
+                use ::spacetimedb::Table;
+                ctx.db.$table().iter()
+            }
+
+            fn as_map(
+                ctx: &::spacetimedb::ReducerContext,
+            ) -> ::std::collections::HashMap<u64, Self> {
+                use ::spacetimedb::Table;
+                ctx.db
+                    .$table()
+                    .iter()
+                    .map(|enemy| (enemy.id, enemy))
+                    .collect()
+            }
+
+            fn as_vec(ctx: &::spacetimedb::ReducerContext) -> Vec<Self> {
+                use ::spacetimedb::Table;
+                ctx.db.$table().iter().collect()
+            }
+
+            fn update(self, ctx: &::spacetimedb::ReducerContext) -> Self {
+                let enemy = ctx.db.$table().id().update(self);
+                $crate::enemy::Enemy::on_saved(&enemy, ctx);
+                enemy
+            }
+
+            fn delete(&self, ctx: &::spacetimedb::ReducerContext) {
+                use ::spacetime_engine::utils::WorldEntity;
+                if let Some(agent) = ::spacetime_engine::navigation::NavigationAgent::find(
+                    ctx,
+                    self.navigation_agent_id,
+                ) {
+                    agent.delete(ctx);
+                }
+                self.body.delete(ctx);
+                $crate::behavior_debug::BehaviorTrace::delete_for(
+                    ctx,
+                    <$enemy as $crate::enemy::Enemy>::ARCHETYPE_ID,
+                    self.id,
+                );
+                ctx.db.$table().id().delete(self.id);
+                $crate::enemy::Enemy::on_deleted(self, ctx);
+            }
+
+            fn clear(ctx: &::spacetimedb::ReducerContext) {
+                use ::spacetimedb::Table;
+                for enemy in ctx.db.$table().iter() {
+                    enemy.delete(ctx);
+                }
+            }
+
+            fn count(ctx: &::spacetimedb::ReducerContext) -> u64 {
+                use ::spacetimedb::Table;
+                ctx.db.$table().count()
+            }
+        }
+
+        impl $crate::enemy::EnemyFields for $enemy {
+            fn id(&self) -> u64 {
+                self.id
+            }
+
+            fn position(&self) -> ::spacetime_engine::math::Vec3 {
+                self.position
+            }
+
+            fn position_mut(&mut self) -> &mut ::spacetime_engine::math::Vec3 {
+                &mut self.position
+            }
+
+            fn navigation_agent_id(&self) -> ::spacetime_engine::navigation::NavigationAgentId {
+                self.navigation_agent_id
+            }
+
+            fn navigation_agent_id_mut(
+                &mut self,
+            ) -> &mut ::spacetime_engine::navigation::NavigationAgentId {
+                &mut self.navigation_agent_id
+            }
+
+            fn health_mut(&mut self) -> &mut f32 {
+                &mut self.health
+            }
+
+            fn blackboard_mut(&mut self) -> &mut $crate::blackboard::Blackboard {
+                &mut self.blackboard
+            }
+
+            fn stuck_mut(&mut self) -> &mut $crate::stuck::StuckTracker {
+                &mut self.stuck
+            }
+
+            fn barricade_id_mut(&mut self) -> &mut Option<u64> {
+                &mut self.barricade_id
+            }
+
+            fn body(&self) -> &$crate::enemy_body::EnemyBody {
+                &self.body
+            }
+
+            fn target_player(&self) -> Option<u64> {
+                self.target_player
+            }
+        }
+    };
+}
+pub(crate) use impl_enemy_entity;
+
+/// Runs the behavior tree of the archetype `E`, built with the `A` actions, on every enemy of that archetype.
+pub fn tick_enemies<E, A>(ctx: &ReducerContext, scheduled_at: ScheduleAt) -> Result<(), String>
+where
+    E: Enemy + BehaviorExecutor<A>,
+    A: Serialize + DeserializeOwned + Debug + 'static,
+{
+    let delta_time = get_delta_time(scheduled_at);
+    let world = World::find(ctx, WORLD_ID).expect("World not found");
+    let tree_id = ArchetypeBehaviorTree::find_tree_id::<A>(ctx, E::ARCHETYPE_ID)?;
+    let mut enemies = E::as_vec(ctx);
+    E::before_tick(ctx, &mut enemies);
+    tick_behavior(ctx, &world, tree_id, delta_time, &mut enemies);
+    Ok(())
+}
+
+/// Moves every enemy of type `E` along with its navigation agent, whose position and
+/// whether it is moving are given by `agent_state`.
+pub fn sync_enemies<E: Enemy>(
+    ctx: &ReducerContext,
+    agent_state: impl Fn(NavigationAgentId) -> Option<(Vec3, bool)>,
+) {
+    for mut enemy in E::iter(ctx) {
+        let Some((position, is_moving)) = agent_state(enemy.navigation_agent_id()) else {
+            continue;
+        };
+
+        *enemy.position_mut() = position;
+        enemy.stuck_mut().sample(position, is_moving);
+        enemy.body().sync(ctx, position);
+        enemy.on_moved(ctx);
+        enemy.update(ctx);
+    }
+}
+
+/// Number of enemies alive, across every archetype.
+pub fn count_enemies(ctx: &ReducerContext) -> u64 {
+    Zombie::count(ctx)
+        + SpitterZombie::count(ctx)
+        + RunnerZombie::count(ctx)
+        + BruteZombie::count(ctx)
+        + ExploderZombie::count(ctx)
+        + ScreamerZombie::count(ctx)
+        + BossZombie::count(ctx)
+}
+
+/// Whether the enemy `id` of the archetype `archetype_id` exists.
+pub fn enemy_exists(ctx: &ReducerContext, archetype_id: ArchetypeId, id: u64) -> bool {
+    match archetype_id {
+        ZOMBIE_ARCHETYPE_ID => Zombie::find(ctx, id).is_some(),
+        SPITTER_ZOMBIE_ARCHETYPE_ID => SpitterZombie::find(ctx, id).is_some(),
+        RUNNER_ZOMBIE_ARCHETYPE_ID => RunnerZombie::find(ctx, id).is_some(),
+        BRUTE_ZOMBIE_ARCHETYPE_ID => BruteZombie::find(ctx, id).is_some(),
+        EXPLODER_ZOMBIE_ARCHETYPE_ID => ExploderZombie::find(ctx, id).is_some(),
+        SCREAMER_ZOMBIE_ARCHETYPE_ID => ScreamerZombie::find(ctx, id).is_some(),
+        BOSS_ZOMBIE_ARCHETYPE_ID => BossZombie::find(ctx, id).is_some(),
+        _ => false,
+    }
+}
+
+/// Kills every enemy of type `E`, without awarding any points. [`Enemy::on_death`] is
+/// skipped, a nuke doesn't set off the exploders it kills.
+pub fn kill_all<E: Enemy>(ctx: &ReducerContext) {
+    for enemy in E::iter(ctx) {
+        enemy.delete(ctx);
+    }
+}
+
+/// Clears the target of every enemy of type `E` targeting `player_id`.
+pub fn clear_target_player<E: Enemy>(ctx: &ReducerContext, player_id: u64) {
+    for mut enemy in E::iter(ctx).filter(|enemy| enemy.target_player() == Some(player_id)) {
+        enemy.clear_target();
+        enemy.update(ctx);
+    }
+}
+
+/// Damages every enemy of type `E` within `radius` of `center` and pushes it `knockback` meters away.
+pub fn damage_enemies_in_radius<E: Enemy>(
+    ctx: &ReducerContext,
+    center: Vec3,
+    radius: f32,
+    damage: f32,
+    knockback: f32,
+) {
+    for mut enemy in E::iter(ctx).filter(|enemy| enemy.position().distance(&center) <= radius) {
+        let position = enemy.position();
+        enemy.knock_back(
+            ctx,
+            point_at_angle(position, angle_to(center, position), knockback),
+        );
+        enemy.damage(ctx, damage, None);
+    }
+}
+
+/// Sends every enemy of type `E` within `radius` of `center` after `player`, unless it already
+/// goes after a closer player.
+pub fn alert_enemies_in_radius<E: Enemy>(
+    ctx: &ReducerContext,
+    center: Vec3,
+    radius: f32,
+    player: &Player,
+) {
+    for mut enemy in E::iter(ctx).filter(|enemy| enemy.position().distance(&center) <= radius) {
+        // Only enemies without a target, or going after a player farther away, answer the call
+        let distance = player.position.distance(&enemy.position());
+        let is_closer = match enemy.target_player() {
+            Some(target) if target == player.id => false,
+            Some(target) => Player::find(ctx, target)
+                .is_none_or(|target| target.position.distance(&enemy.position()) > distance),
+            None => true,
+        };
+        if !is_closer {
+            continue;
+        }
+
+        enemy.set_target(player.id);
+        if let Some(mut agent) = NavigationAgent::find(ctx, enemy.navigation_agent_id()) {
+            agent
+                .set_destination(Some(enemy.chase_destination(ctx, player)))
+                .set_paused(false);
+            agent.update(ctx);
+        }
+        enemy.update(ctx);
+    }
+}
@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+use spacetime_engine::{
+    behavior::Status,
+    navigation::NavigationAgent,
+    utils::{Entity, WorldEntity},
+};
+use spacetimedb::{ReducerContext, TimeDuration};
+
+use crate::{
+    barricades::Barricade,
+    blackboard::ATTACK_COOLDOWN_TIMER,
+    enemy::{Enemy, KNOCK_BACK_TIMER},
+    stuck::{side_step, REPATH_ATTEMPTS, SIDE_STEP_ATTEMPTS, STUCK_RECOVERY_TIMER},
+};
+
+/// Enemies tear a plank down from this close to the barricade.
+const OBSTACLE_ATTACK_RANGE: f32 = 1.2;
+const OBSTACLE_ATTACK_DAMAGE: f32 = 20.0;
+const OBSTACLE_ATTACK_COOLDOWN_MICROS: i64 = 1_000_000;
+
+/// Behavior tree actions shared by every enemy archetype, wrapped by the action enum of each archetype.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum EnemyAction {
+    IsStuck,
+    IsRecovering,
+    IsKnockedBack,
+    Repath,
+    SideStep,
+    TeleportToSpawn,
+    IsMoving,
+    IsTargetReached,
+    TargetRandomPlayer,
+    Chase,
+    IsBehindBarricade,
+    AttackObstacle,
+}
+
+impl EnemyAction {
+    pub fn run<E: Enemy>(
+        &self,
+        ctx: &ReducerContext,
+        enemy: &mut E,
+        mut agent: NavigationAgent,
+    ) -> Status {
+        match self {
+            EnemyAction::IsStuck => match enemy.stuck_mut().is_stuck() {
+                true => Status::Success,
+                false => Status::Failure,
+            },
+            EnemyAction::IsRecovering => {
+                match enemy
+                    .blackboard_mut()
+                    .timer_elapsed(ctx, STUCK_RECOVERY_TIMER)
+                {
+                    true => Status::Failure,
+                    false => Status::Success,
+                }
+            }
+            EnemyAction::IsKnockedBack => {
+                match enemy.blackboard_mut().timer_elapsed(ctx, KNOCK_BACK_TIMER) {
+                    true => Status::Failure,
+                    false => Status::Success,
+                }
+            }
+            EnemyAction::Repath => {
+                let Some(player) = enemy.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
+
+                if !enemy.stuck_mut().begin_recovery(REPATH_ATTEMPTS) {
+                    return Status::Failure;
+                }
+
+                // Requesting the destination again makes the agent compute a new path
+                agent
+                    .set_destination(Some(enemy.chase_destination(ctx, &player)))
+                    .set_paused(false);
+                agent.update(ctx);
+
+                enemy.clone().update(ctx);
+                Status::Success
+            }
+            EnemyAction::SideStep => {
+                if !enemy.stuck_mut().begin_recovery(SIDE_STEP_ATTEMPTS) {
+                    return Status::Failure;
+                }
+
+                let destination = side_step(ctx, enemy.blackboard_mut(), agent.position());
+                agent.set_destination(Some(destination)).set_paused(false);
+                agent.update(ctx);
+
+                enemy.clone().update(ctx);
+                Status::Success
+            }
+            EnemyAction::TeleportToSpawn => {
+                let Some(spawn_point) = enemy.recovery_spawn_point(ctx) else {
+                    return Status::Failure;
+                };
+
+                enemy.teleport(ctx, spawn_point.position);
+                enemy.clone().update(ctx);
+                Status::Success
+            }
+            EnemyAction::IsMoving => match agent.is_moving() {
+                true => Status::Success,
+                false => Status::Failure,
+            },
+            EnemyAction::IsTargetReached => match agent.has_reached_destination() {
+                true => Status::Success,
+                false => Status::Failure,
+            },
+            EnemyAction::TargetRandomPlayer => {
+                let Some(target) = enemy.choose_target(ctx) else {
+                    return Status::Failure;
+                };
+
+                enemy.set_target(target.id);
+                agent
+                    .set_destination(Some(enemy.chase_destination(ctx, &target)))
+                    .set_paused(false);
+                agent.update(ctx);
+
+                enemy.clone().update(ctx);
+                Status::Success
+            }
+            EnemyAction::Chase => {
+                // Target player not found, fail the action to re-target
+                let Some(player) = enemy.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
+
+                agent
+                    .set_destination(Some(enemy.chase_destination(ctx, &player)))
+                    .set_paused(false);
+                agent.update(ctx);
+
+                enemy.blackboard_mut().last_seen_position = Some(player.position);
+                enemy.clone().update(ctx);
+                Status::Success
+            }
+            EnemyAction::IsBehindBarricade => match enemy.barricade_id_mut() {
+                Some(_) => Status::Success,
+                None => Status::Failure,
+            },
+            EnemyAction::AttackObstacle => {
+                let barricade = enemy
+                    .barricade_id_mut()
+                    .and_then(|barricade_id| Barricade::find(ctx, barricade_id));
+
+                // Nothing left in the way, get in and go after the players
+                let Some(barricade) = barricade.filter(|barricade| !barricade.is_broken()) else {
+                    *enemy.barricade_id_mut() = None;
+                    enemy.clone().update(ctx);
+                    return Status::Failure;
+                };
+
+                if barricade.position.distance(&agent.position()) > OBSTACLE_ATTACK_RANGE {
+                    agent
+                        .set_destination(Some(barricade.position))
+                        .set_paused(false);
+                    agent.update(ctx);
+                    return Status::Success;
+                }
+
+                if !enemy
+                    .blackboard_mut()
+                    .timer_elapsed(ctx, ATTACK_COOLDOWN_TIMER)
+                {
+                    return Status::Success;
+                }
+
+                barricade.damage(ctx, OBSTACLE_ATTACK_DAMAGE);
+                enemy.blackboard_mut().set_timer(
+                    ATTACK_COOLDOWN_TIMER,
+                    ctx.timestamp + TimeDuration::from_micros(OBSTACLE_ATTACK_COOLDOWN_MICROS),
+                );
+                enemy.clone().update(ctx);
+                Status::Success
+            }
+        }
+    }
+}
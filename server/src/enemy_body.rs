@@ -0,0 +1,90 @@
+use spacetime_engine::{
+    collisions::{ColliderId, RigidBody},
+    math::Vec3,
+    utils::WorldEntity,
+};
+use spacetimedb::{ReducerContext, SpacetimeType};
+
+use crate::{
+    archetypes::ArchetypeId,
+    constants::{ENEMY_HEAD_COLLIDER_ID, WORLD_ID},
+    tables::entity_rigid_body::{EntityKind, EntityRigidBody},
+};
+
+/// Radius of the sphere collider of enemy heads, see `init`.
+pub const ENEMY_HEAD_RADIUS: f32 = 0.25;
+
+/// Capsule collider of an enemy's body, standing on the enemy's position.
+/// `height` includes both caps. Sizes the collider created in `init` and the shape shots
+/// are tested against.
+pub struct BodyCapsule {
+    pub radius: f32,
+    pub height: f32,
+}
+
+/// Rigid bodies of an enemy, a capsule for its body and a sphere for its head.
+/// They follow the navigation agent, moved on every world tick.
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct EnemyBody {
+    pub rigid_body_id: u64,
+    pub head_rigid_body_id: u64,
+    /// Height of the center of the head above the feet of the enemy.
+    pub head_height: f32,
+}
+
+impl EnemyBody {
+    pub fn create(
+        ctx: &ReducerContext,
+        collider_id: ColliderId,
+        head_height: f32,
+        position: Vec3,
+    ) -> Self {
+        let body = Self {
+            rigid_body_id: Self::create_rigid_body(ctx, collider_id),
+            head_rigid_body_id: Self::create_rigid_body(ctx, ENEMY_HEAD_COLLIDER_ID),
+            head_height,
+        };
+        body.sync(ctx, position);
+        body
+    }
+
+    fn create_rigid_body(ctx: &ReducerContext, collider_id: ColliderId) -> u64 {
+        RigidBody::builder()
+            .world_id(WORLD_ID)
+            .collider_id(collider_id)
+            .build()
+            .insert(ctx)
+            .id
+    }
+
+    /// Maps both rigid bodies back to the enemy, once it has an id.
+    pub fn register(&self, ctx: &ReducerContext, archetype_id: ArchetypeId, enemy_id: u64) {
+        let kind = EntityKind::Enemy(archetype_id);
+        EntityRigidBody::register(ctx, self.rigid_body_id, kind, enemy_id);
+        EntityRigidBody::register(ctx, self.head_rigid_body_id, kind, enemy_id);
+    }
+
+    pub fn head_center(&self, position: Vec3) -> Vec3 {
+        Vec3::new(position.x, position.y + self.head_height, position.z)
+    }
+
+    pub fn sync(&self, ctx: &ReducerContext, position: Vec3) {
+        if let Some(mut rb) = RigidBody::find(ctx, self.rigid_body_id) {
+            rb.position = position;
+            rb.update(ctx);
+        }
+        if let Some(mut rb) = RigidBody::find(ctx, self.head_rigid_body_id) {
+            rb.position = self.head_center(position);
+            rb.update(ctx);
+        }
+    }
+
+    pub fn delete(&self, ctx: &ReducerContext) {
+        for rigid_body_id in [self.rigid_body_id, self.head_rigid_body_id] {
+            if let Some(rb) = RigidBody::find(ctx, rigid_body_id) {
+                rb.delete(ctx);
+            }
+            EntityRigidBody::unregister(ctx, rigid_body_id);
+        }
+    }
+}
@@ -0,0 +1,430 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use spacetime_engine::{
+    behavior::{Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
+    collisions::{Trigger, TriggerId},
+    math::Vec3,
+    navigation::{DestinationReachedCondition, NavigationAgent, NavigationAgentId},
+    utils::{Entity, WorldEntity},
+    world::World,
+};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::{
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    barricades::Barricade,
+    behavior_debug::{trace_action, tree_leaves},
+    blackboard::Blackboard,
+    boss_zombie::BossZombie,
+    brute_zombie::BruteZombie,
+    constants::{
+        EXPLODER_EXPLOSION_COLLIDER_ID, EXPLODER_ZOMBIE_ARCHETYPE_ID, EXPLODER_ZOMBIE_COLLIDER_ID,
+    },
+    enemy::{damage_enemies_in_radius, impl_enemy_entity, tick_enemies, Enemy},
+    enemy_actions::EnemyAction,
+    enemy_body::{BodyCapsule, EnemyBody},
+    math::{angle_to, point_at_angle},
+    noise::{NoiseEvent, NoiseKind},
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
+    spitter_zombie::SpitterZombie,
+    stuck::StuckTracker,
+    tables::{entity_rigid_body::EntityRigidBody, zombie::Zombie},
+    zombies_spawner::ZombieSpawnPoint,
+};
+
+const EXPLODER_ZOMBIE_HEALTH: f32 = 40.0;
+const EXPLODER_ZOMBIE_KILL_POINTS: u32 = 90;
+const EXPLODER_ZOMBIE_HEAD_HEIGHT: f32 = 1.4;
+
+/// The fuse is lit when the target gets this close.
+const EXPLODER_FUSE_RANGE: f32 = 2.0;
+const EXPLODER_FUSE_MICROS: i64 = 600_000;
+
+/// Must match the radius of the explosion collider created in `init`.
+const EXPLOSION_RADIUS: f32 = 4.0;
+const EXPLOSION_DAMAGE: f32 = 45.0;
+const EXPLOSION_KNOCKBACK: f32 = 8.0;
+
+pub type ExploderZombieId = u64;
+
+#[table(name = exploder_zombie, public)]
+#[derive(Clone)]
+pub struct ExploderZombie {
+    #[primary_key]
+    #[auto_inc]
+    pub id: ExploderZombieId,
+    #[index(btree)]
+    pub navigation_agent_id: NavigationAgentId,
+    pub position: Vec3,
+    pub health: f32,
+    pub target_player: Option<u64>,
+    /// Set once the fuse is lit, the zombie detonates at that time.
+    pub detonates_at: Option<Timestamp>,
+    pub barricade_id: Option<u64>,
+    pub blackboard: Blackboard,
+    pub stuck: StuckTracker,
+    pub body: EnemyBody,
+}
+
+impl_enemy_entity!(ExploderZombie, exploder_zombie);
+
+impl Enemy for ExploderZombie {
+    const ARCHETYPE_ID: ArchetypeId = EXPLODER_ZOMBIE_ARCHETYPE_ID;
+    const BODY: BodyCapsule = BodyCapsule {
+        radius: 0.55,
+        height: 1.6,
+    };
+
+    fn kill_points(&self) -> u32 {
+        EXPLODER_ZOMBIE_KILL_POINTS
+    }
+
+    fn set_target(&mut self, player_id: u64) {
+        self.target_player = Some(player_id);
+    }
+
+    fn clear_target(&mut self) {
+        self.target_player = None;
+    }
+
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId {
+        NavigationAgent::builder()
+            .desired_speed(4.5)
+            .max_speed(6.0)
+            .radius(0.4)
+            .target_reached_condition(DestinationReachedCondition::Distance(Some(1.0)))
+            .position(position)
+            .build()
+            .insert(ctx)
+            .id()
+    }
+
+    /// Killing an exploder still sets it off.
+    fn on_death(&self, ctx: &ReducerContext) {
+        Explosion::create(ctx, self.position);
+    }
+}
+
+impl ExploderZombie {
+    pub fn create(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint) -> Self {
+        let position = spawn_point.position;
+        let exploder = ExploderZombie {
+            id: 0,
+            navigation_agent_id: Self::create_navigation_agent(ctx, position),
+            position,
+            health: EXPLODER_ZOMBIE_HEALTH,
+            target_player: None,
+            detonates_at: None,
+            barricade_id: Barricade::find_for_spawn_point(ctx, spawn_point.id)
+                .map(|barricade| barricade.id),
+            blackboard: Blackboard::default(),
+            stuck: StuckTracker::new(position),
+            body: EnemyBody::create(
+                ctx,
+                EXPLODER_ZOMBIE_COLLIDER_ID,
+                EXPLODER_ZOMBIE_HEAD_HEIGHT,
+                position,
+            ),
+        }
+        .insert(ctx);
+        exploder
+            .body
+            .register(ctx, EXPLODER_ZOMBIE_ARCHETYPE_ID, exploder.id);
+        exploder
+    }
+}
+
+/// Short lived sphere trigger damaging and knocking back everything around a detonated exploder.
+#[table(name = explosion, public, scheduled(tick_explosion))]
+pub struct Explosion {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub trigger_id: TriggerId,
+    pub position: Vec3,
+    pub created_at: Timestamp,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl Entity for Explosion {
+    fn insert(self, ctx: &ReducerContext) -> Self {
+        ctx.db.explosion().insert(self)
+    }
+
+    fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
+        ctx.db.explosion().id().find(id)
+    }
+
+    fn iter(ctx: &ReducerContext) -> impl Iterator<Item = Self> {
+        ctx.db.explosion().iter()
+    }
+
+    fn as_map(ctx: &ReducerContext) -> HashMap<u64, Self> {
+        ctx.db
+            .explosion()
+            .iter()
+            .map(|explosion| (explosion.id, explosion))
+            .collect()
+    }
+
+    fn as_vec(ctx: &ReducerContext) -> Vec<Self> {
+        ctx.db.explosion().iter().collect()
+    }
+
+    fn update(self, ctx: &ReducerContext) -> Self {
+        ctx.db.explosion().id().update(self)
+    }
+
+    fn delete(&self, ctx: &ReducerContext) {
+        if let Some(trigger) = Trigger::find(ctx, self.trigger_id) {
+            trigger.delete(ctx);
+        }
+        ctx.db.explosion().id().delete(self.id);
+    }
+
+    fn clear(ctx: &ReducerContext) {
+        for explosion in ctx.db.explosion().iter() {
+            explosion.delete(ctx);
+        }
+    }
+
+    fn count(ctx: &ReducerContext) -> u64 {
+        ctx.db.explosion().count()
+    }
+}
+
+impl Explosion {
+    pub fn create(ctx: &ReducerContext, position: Vec3) -> Self {
+        let trigger_id = Trigger::builder()
+            .position(position)
+            .collider_id(EXPLODER_EXPLOSION_COLLIDER_ID)
+            .build()
+            .insert(ctx)
+            .id;
+        NoiseEvent::emit(ctx, NoiseKind::Explosion, position, None);
+        Explosion {
+            id: 0,
+            trigger_id,
+            position,
+            created_at: ctx.timestamp,
+            // Leave the world tick enough time to fill the trigger before it is read
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(200_000)),
+        }
+        .insert(ctx)
+    }
+}
+
+#[reducer]
+fn tick_explosion(ctx: &ReducerContext, tick: Explosion) {
+    let Some(trigger) = Trigger::find(ctx, tick.trigger_id) else {
+        tick.delete(ctx);
+        return;
+    };
+
+    for rigid_body_id in &trigger.entities_inside {
+        let Some(player) = EntityRigidBody::find_player(ctx, *rigid_body_id) else {
+            continue;
+        };
+
+        let impulse = point_at_angle(
+            Vec3::ZERO,
+            angle_to(tick.position, player.position),
+            EXPLOSION_KNOCKBACK,
+        );
+        player
+            .damage(ctx, EXPLOSION_DAMAGE)
+            .knock_back(ctx, impulse);
+    }
+
+    // Enemies are hit based on their distance to the explosion, their bodies lag a world tick behind.
+    // Exploders caught in the blast detonate in turn, on their own explosion's tick.
+    damage_enemies_in_radius::<Zombie>(
+        ctx,
+        tick.position,
+        EXPLOSION_RADIUS,
+        EXPLOSION_DAMAGE,
+        EXPLOSION_KNOCKBACK,
+    );
+    damage_enemies_in_radius::<SpitterZombie>(
+        ctx,
+        tick.position,
+        EXPLOSION_RADIUS,
+        EXPLOSION_DAMAGE,
+        EXPLOSION_KNOCKBACK,
+    );
+    damage_enemies_in_radius::<RunnerZombie>(
+        ctx,
+        tick.position,
+        EXPLOSION_RADIUS,
+        EXPLOSION_DAMAGE,
+        EXPLOSION_KNOCKBACK,
+    );
+    damage_enemies_in_radius::<BruteZombie>(
+        ctx,
+        tick.position,
+        EXPLOSION_RADIUS,
+        EXPLOSION_DAMAGE,
+        EXPLOSION_KNOCKBACK,
+    );
+    damage_enemies_in_radius::<ExploderZombie>(
+        ctx,
+        tick.position,
+        EXPLOSION_RADIUS,
+        EXPLOSION_DAMAGE,
+        EXPLOSION_KNOCKBACK,
+    );
+    damage_enemies_in_radius::<ScreamerZombie>(
+        ctx,
+        tick.position,
+        EXPLOSION_RADIUS,
+        EXPLOSION_DAMAGE,
+        EXPLOSION_KNOCKBACK,
+    );
+    damage_enemies_in_radius::<BossZombie>(
+        ctx,
+        tick.position,
+        EXPLOSION_RADIUS,
+        EXPLOSION_DAMAGE,
+        EXPLOSION_KNOCKBACK,
+    );
+
+    tick.delete(ctx);
+}
+
+#[table(name = exploder_zombie_update_tick, scheduled(tick_exploder_zombie))]
+pub struct ExploderZombieUpdateTick {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl ExploderZombieUpdateTick {
+    pub fn schedule(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .exploder_zombie_update_tick()
+            .insert(ExploderZombieUpdateTick {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(250_000)),
+            })
+    }
+}
+
+#[reducer]
+fn tick_exploder_zombie(
+    ctx: &ReducerContext,
+    tick: ExploderZombieUpdateTick,
+) -> Result<(), String> {
+    tick_enemies::<ExploderZombie, ExploderZombieAction>(ctx, tick.scheduled_at)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum ExploderZombieAction {
+    IsFuseLit,
+    IsInFuseRange,
+    LightFuse,
+    Detonate,
+    Enemy(EnemyAction),
+}
+
+impl BehaviorExecutor<ExploderZombieAction> for ExploderZombie {
+    fn run_action(
+        &mut self,
+        ctx: &ReducerContext,
+        _world: &World,
+        _dt: f32,
+        action: &ExploderZombieAction,
+    ) -> Status {
+        let status = self.execute_action(ctx, action);
+        trace_action(ctx, EXPLODER_ZOMBIE_ARCHETYPE_ID, self.id, action, &status);
+        status
+    }
+}
+
+impl ExploderZombie {
+    fn execute_action(&mut self, ctx: &ReducerContext, action: &ExploderZombieAction) -> Status {
+        let mut agent = NavigationAgent::find(ctx, self.navigation_agent_id)
+            .expect("NavigationAgent not found");
+        match action {
+            ExploderZombieAction::IsFuseLit => match self.detonates_at.is_some() {
+                true => Status::Success,
+                false => Status::Failure,
+            },
+            ExploderZombieAction::IsInFuseRange => {
+                let Some(player) = self.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
+
+                match player.position.distance(&agent.position()) <= EXPLODER_FUSE_RANGE {
+                    true => Status::Success,
+                    false => Status::Failure,
+                }
+            }
+            ExploderZombieAction::LightFuse => {
+                agent.set_paused(true);
+                agent.update(ctx);
+
+                self.detonates_at =
+                    Some(ctx.timestamp + TimeDuration::from_micros(EXPLODER_FUSE_MICROS));
+                self.clone().update(ctx);
+                Status::Success
+            }
+            ExploderZombieAction::Detonate => {
+                if self.detonates_at.is_some_and(|at| ctx.timestamp < at) {
+                    return Status::Success;
+                }
+
+                Explosion::create(ctx, self.position);
+                self.delete(ctx);
+                Status::Success
+            }
+            ExploderZombieAction::Enemy(action) => action.run(ctx, self, agent),
+        }
+    }
+}
+
+pub fn create_exploder_zombie_behavior_tree(ctx: &ReducerContext) {
+    let bt = Select(vec![
+        // Tear down the barricade of our spawn point before getting in
+        Sequence(vec![
+            Action(ExploderZombieAction::Enemy(EnemyAction::IsBehindBarricade)),
+            Action(ExploderZombieAction::Enemy(EnemyAction::AttackObstacle)),
+        ]),
+        // Once the fuse is lit nothing stops the detonation
+        Sequence(vec![
+            Action(ExploderZombieAction::IsFuseLit),
+            Action(ExploderZombieAction::Detonate),
+        ]),
+        // Light the fuse when close enough to the target
+        Sequence(vec![
+            Action(ExploderZombieAction::IsInFuseRange),
+            Action(ExploderZombieAction::LightFuse),
+        ]),
+        // If we are stuck, repath, then side-step and finally teleport to the nearest spawn
+        Sequence(vec![
+            Action(ExploderZombieAction::Enemy(EnemyAction::IsStuck)),
+            Select(vec![
+                Action(ExploderZombieAction::Enemy(EnemyAction::Repath)),
+                Action(ExploderZombieAction::Enemy(EnemyAction::SideStep)),
+                Action(ExploderZombieAction::Enemy(EnemyAction::TeleportToSpawn)),
+            ]),
+        ]),
+        // Let a side-step play out before chasing again
+        Action(ExploderZombieAction::Enemy(EnemyAction::IsRecovering)),
+        // Let a knock back push us before chasing again
+        Action(ExploderZombieAction::Enemy(EnemyAction::IsKnockedBack)),
+        // Run at the target, or pick one if we have none
+        Action(ExploderZombieAction::Enemy(EnemyAction::Chase)),
+        Action(ExploderZombieAction::Enemy(EnemyAction::TargetRandomPlayer)),
+    ]);
+    let leaves = tree_leaves(&bt);
+    let tree_id = BehaviorTree::create(ctx, bt).id;
+    ArchetypeBehaviorTree::register::<ExploderZombieAction>(
+        ctx,
+        EXPLODER_ZOMBIE_ARCHETYPE_ID,
+        tree_id,
+        leaves,
+    );
+}
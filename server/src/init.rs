@@ -1,16 +1,31 @@
-use log::info;
 use spacetime_engine::{
     collisions::Collider,
     math::Vec3,
-    navigation::{import_external_navmesh, ExternalNavMesh},
+    navigation::ExternalNavMesh,
     utils::{Entity, WorldEntity},
     world::World,
 };
 use spacetimedb::{reducer, ReducerContext};
 
 use crate::{
-    constants::WORLD_ID,
-    spitter_zombie::{create_spitter_zombie_behavior_tree, SpitterZombieUpdateTick},
+    admins::Admin,
+    boss_zombie::{create_boss_zombie_behavior_tree, BossZombie, BossZombieUpdateTick},
+    brute_zombie::{create_brute_zombie_behavior_tree, BruteZombie, BruteZombieUpdateTick},
+    constants::{STARTING_AREA_ID, WORLD_ID},
+    enemy::Enemy,
+    enemy_body::ENEMY_HEAD_RADIUS,
+    exploder_zombie::{
+        create_exploder_zombie_behavior_tree, ExploderZombie, ExploderZombieUpdateTick,
+    },
+    navmesh::rebuild_navmesh,
+    runner_zombie::{create_runner_zombie_behavior_tree, RunnerZombie, RunnerZombieUpdateTick},
+    screamer_zombie::{
+        create_screamer_zombie_behavior_tree, ScreamerZombie, ScreamerZombieUpdateTick,
+    },
+    spitter_zombie::{create_spitter_zombie_behavior_tree, SpitterZombie, SpitterZombieUpdateTick},
+    tables::zombie::Zombie,
+    wave_director::WaveDirectorTick,
+    weapons::WeaponDefinition,
     world::WorldTick,
     zombies_spawner::{ZombieSpawnPoint, ZombieSpawnTick},
     zombies_tick::{create_zombie_behavior_tree, ZombieUpdateTick},
@@ -20,45 +35,66 @@ use crate::{
 pub fn init(ctx: &ReducerContext) {
     World::builder().debug_collisions(true).build().insert(ctx);
 
+    // The identity publishing the module owns it
+    Admin::create(ctx, ctx.sender);
+
     create_zombie_behavior_tree(ctx);
     create_spitter_zombie_behavior_tree(ctx);
+    create_runner_zombie_behavior_tree(ctx);
+    create_brute_zombie_behavior_tree(ctx);
+    create_exploder_zombie_behavior_tree(ctx);
+    create_screamer_zombie_behavior_tree(ctx);
+    create_boss_zombie_behavior_tree(ctx);
 
     ZombieUpdateTick::schedule(ctx);
     SpitterZombieUpdateTick::schedule(ctx);
+    RunnerZombieUpdateTick::schedule(ctx);
+    BruteZombieUpdateTick::schedule(ctx);
+    ExploderZombieUpdateTick::schedule(ctx);
+    ScreamerZombieUpdateTick::schedule(ctx);
+    BossZombieUpdateTick::schedule(ctx);
     ZombieSpawnTick::schedule(ctx);
     WorldTick::schedule(ctx);
+    WaveDirectorTick::schedule(ctx);
+
+    WeaponDefinition::seed(ctx);
 
-    ZombieSpawnPoint::create(ctx, Vec3::new(0.0, 0.0, -50.0));
-    ZombieSpawnPoint::create(ctx, Vec3::new(-16.0, 0.0, 0.0));
-    ZombieSpawnPoint::create(ctx, Vec3::new(44.0, 0.0, 2.0));
-    ZombieSpawnPoint::create(ctx, Vec3::new(0.0, 0.0, 40.0));
+    ZombieSpawnPoint::create(ctx, 1, Vec3::new(0.0, 0.0, -50.0), STARTING_AREA_ID);
+    ZombieSpawnPoint::create(ctx, 2, Vec3::new(-16.0, 0.0, 0.0), STARTING_AREA_ID);
+    ZombieSpawnPoint::create(ctx, 3, Vec3::new(44.0, 0.0, 2.0), STARTING_AREA_ID);
+    ZombieSpawnPoint::create(ctx, 4, Vec3::new(0.0, 0.0, 40.0), STARTING_AREA_ID);
+    ZombieSpawnPoint::create_boss_spawn(ctx, 5, Vec3::new(20.0, 0.0, -20.0));
 
     // Player collider
     Collider::capsule(WORLD_ID, 0.5, 1.8).insert(ctx);
     // Spitter AoE collider
     Collider::sphere(WORLD_ID, 3.0).insert(ctx);
+    // Brute charge collider
+    Collider::capsule(WORLD_ID, 1.0, 2.6).insert(ctx);
+    // Exploder explosion collider
+    Collider::sphere(WORLD_ID, 4.0).insert(ctx);
+    // Power-up pickup collider
+    Collider::sphere(WORLD_ID, 1.0).insert(ctx);
+    // Zombie body colliders, sized per archetype
+    insert_body_collider::<Zombie>(ctx);
+    insert_body_collider::<SpitterZombie>(ctx);
+    insert_body_collider::<RunnerZombie>(ctx);
+    insert_body_collider::<BruteZombie>(ctx);
+    insert_body_collider::<ExploderZombie>(ctx);
+    insert_body_collider::<ScreamerZombie>(ctx);
+    insert_body_collider::<BossZombie>(ctx);
+    // Zombie head collider, for headshots
+    Collider::sphere(WORLD_ID, ENEMY_HEAD_RADIUS).insert(ctx);
+}
+
+/// Shots are tested against the same capsule, see `weapons`.
+fn insert_body_collider<E: Enemy>(ctx: &ReducerContext) {
+    Collider::capsule(WORLD_ID, E::BODY.radius, E::BODY.height).insert(ctx);
 }
 
 #[reducer]
 fn editor_upload_navmesh(ctx: &ReducerContext, world_id: u64) {
-    let file = include_str!("../../client/navmesh_export.json");
-    info!("navmesh export file size: {} bytes", file.len());
-    let navmesh =
-        serde_json::from_str::<ExternalNavMesh>(file).expect("Failed to parse navmesh JSON");
-    info!(
-        "Parsed navmesh with {} vertices and {} polygons",
-        navmesh.vertices.len(),
-        navmesh.polygons.len()
-    );
-
-    info!(
-        "Uploading navmesh with {} vertices and {} polygons for world {}",
-        navmesh.vertices.len(),
-        navmesh.polygons.len(),
-        world_id
-    );
-
-    import_external_navmesh(ctx, world_id, navmesh);
+    rebuild_navmesh(ctx, world_id);
 }
 
 #[reducer]
@@ -0,0 +1,125 @@
+use spacetime_engine::math::Vec3;
+use spacetimedb::{reducer, table, ReducerContext, SpacetimeType, Table};
+
+use crate::{
+    admins::Admin,
+    perks::{PerkKind, PlayerPerk},
+    tables::player::Player,
+    weapons::{PlayerWeapon, WeaponKind},
+};
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InteractableKind {
+    /// Gives the weapon, or refills its ammo if the player already owns it.
+    WallWeapon(WeaponKind),
+    /// Refills the ammo of every weapon of the player.
+    AmmoRefill,
+    Perk(PerkKind),
+}
+
+/// World object players can buy from.
+#[table(name = interactable, public)]
+pub struct Interactable {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub kind: InteractableKind,
+    pub position: Vec3,
+    pub cost: u32,
+    /// Players must be within this distance to interact.
+    pub radius: f32,
+}
+
+/// Interactable as placed in the level editor.
+#[derive(SpacetimeType)]
+pub struct ImportedInteractable {
+    pub kind: InteractableKind,
+    pub position: Vec3,
+    pub cost: u32,
+    pub radius: f32,
+}
+
+impl Interactable {
+    pub fn create(
+        ctx: &ReducerContext,
+        kind: InteractableKind,
+        position: Vec3,
+        cost: u32,
+        radius: f32,
+    ) -> Self {
+        ctx.db.interactable().insert(Interactable {
+            id: 0,
+            kind,
+            position,
+            cost,
+            radius,
+        })
+    }
+}
+
+#[reducer]
+fn player_interact(ctx: &ReducerContext, interactable_id: u64) -> Result<(), String> {
+    let player = Player::find_by_identity(ctx, ctx.sender).ok_or("Player not found")?;
+    let interactable = ctx
+        .db
+        .interactable()
+        .id()
+        .find(interactable_id)
+        .ok_or("Interactable not found")?;
+
+    if player.position.distance(&interactable.position) > interactable.radius {
+        return Err("Too far from the interactable".to_string());
+    }
+
+    if let InteractableKind::Perk(kind) = interactable.kind {
+        if PlayerPerk::has(ctx, player.id, kind) {
+            return Err(format!("{:?} already owned", kind));
+        }
+    }
+
+    let player = player.spend_points(ctx, interactable.cost)?;
+    match interactable.kind {
+        InteractableKind::WallWeapon(kind) => {
+            match PlayerWeapon::find_owned(ctx, player.id, kind) {
+                Some(weapon) => {
+                    weapon.refill(ctx);
+                }
+                None => {
+                    PlayerWeapon::give(ctx, player.id, kind);
+                }
+            }
+        }
+        InteractableKind::AmmoRefill => PlayerWeapon::refill_all(ctx, player.id),
+        InteractableKind::Perk(kind) => {
+            PlayerPerk::give(ctx, player, kind);
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces every interactable with the ones exported from the level.
+#[reducer]
+fn editor_import_interactables(
+    ctx: &ReducerContext,
+    interactables: Vec<ImportedInteractable>,
+) -> Result<(), String> {
+    Admin::require(ctx)?;
+
+    for interactable in ctx.db.interactable().iter() {
+        ctx.db.interactable().id().delete(interactable.id);
+    }
+
+    log::info!("Importing {} interactables", interactables.len());
+    for interactable in interactables {
+        Interactable::create(
+            ctx,
+            interactable.kind,
+            interactable.position,
+            interactable.cost,
+            interactable.radius,
+        );
+    }
+
+    Ok(())
+}
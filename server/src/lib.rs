@@ -1,10 +1,37 @@
+mod admins;
+mod archetypes;
+mod barricades;
+mod behavior_debug;
+mod blackboard;
+mod boss_zombie;
+mod brute_zombie;
 mod constants;
+mod doors;
+mod enemy;
+mod enemy_actions;
+mod enemy_body;
+mod exploder_zombie;
 mod init;
+mod interactables;
 mod lifecycle;
+mod math;
+mod melee;
+mod navmesh;
+mod noise;
+mod perks;
 mod players;
+mod power_ups;
+mod runner_zombie;
+mod screamer_zombie;
+mod spitter_projectile;
 mod spitter_zombie;
+mod stuck;
+mod surround;
 mod tables;
 mod types;
+mod wave_director;
+mod weapons;
 mod world;
 mod zombies_spawner;
 mod zombies_tick;
+mod zones;
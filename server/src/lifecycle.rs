@@ -5,12 +5,26 @@ use spacetime_engine::{
 use spacetimedb::{reducer, ReducerContext, Table};
 
 use crate::{
+    barricades::Barricade,
+    boss_zombie::BossZombie,
+    brute_zombie::BruteZombie,
     constants::WORLD_ID,
-    spitter_zombie::{SpitterAoE, SpitterZombie},
+    doors::Door,
+    exploder_zombie::{ExploderZombie, Explosion},
+    noise::NoiseEvent,
+    power_ups::ActivePowerUp,
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
+    spitter_projectile::SpitterProjectile,
+    spitter_zombie::SpitterZombie,
     tables::{
         player::{player, Player},
         zombie::Zombie,
+        zombie_pack::ZombiePack,
     },
+    wave_director::Round,
+    zombies_spawner::ZombieSpawnRequest,
+    zones::Zone,
 };
 
 #[reducer(client_disconnected)]
@@ -23,7 +37,21 @@ fn on_disconnect(ctx: &ReducerContext) {
     if ctx.db.player().count() == 0 {
         Zombie::clear(ctx);
         SpitterZombie::clear(ctx);
-        SpitterAoE::clear(ctx);
+        RunnerZombie::clear(ctx);
+        BruteZombie::clear(ctx);
+        ExploderZombie::clear(ctx);
+        Explosion::clear(ctx);
+        ScreamerZombie::clear(ctx);
+        BossZombie::clear(ctx);
+        ZombiePack::clear(ctx);
+        ZombieSpawnRequest::clear(ctx);
+        NoiseEvent::clear(ctx);
+        SpitterProjectile::clear(ctx);
+        Zone::clear(ctx);
+        ActivePowerUp::clear(ctx);
         Trigger::clear(ctx, WORLD_ID);
+        Round::clear(ctx);
+        Door::reset(ctx);
+        Barricade::rebuild_all(ctx);
     }
 }
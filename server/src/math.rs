@@ -0,0 +1,176 @@
+use spacetime_engine::math::Vec3;
+
+/// Point on the horizontal plane at `distance` from `center` in the direction of `angle` (radians).
+pub fn point_at_angle(center: Vec3, angle: f32, distance: f32) -> Vec3 {
+    Vec3::new(
+        center.x + angle.cos() * distance,
+        center.y,
+        center.z + angle.sin() * distance,
+    )
+}
+
+/// Angle (radians) of the direction from `from` to `to` on the horizontal plane,
+/// such that `point_at_angle(from, angle_to(from, to), d)` lies towards `to`.
+pub fn angle_to(from: Vec3, to: Vec3) -> f32 {
+    (to.z - from.z).atan2(to.x - from.x)
+}
+
+pub fn lerp(from: Vec3, to: Vec3, t: f32) -> Vec3 {
+    Vec3::new(
+        from.x + (to.x - from.x) * t,
+        from.y + (to.y - from.y) * t,
+        from.z + (to.z - from.z) * t,
+    )
+}
+
+pub fn normalize(v: Vec3) -> Vec3 {
+    let length = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+    if length <= f32::EPSILON {
+        return Vec3::ZERO;
+    }
+    Vec3::new(v.x / length, v.y / length, v.z / length)
+}
+
+/// Distance along the ray from `origin` in the normalized `direction` to the sphere,
+/// or `None` if the ray misses it.
+pub fn ray_sphere_distance(
+    origin: Vec3,
+    direction: Vec3,
+    center: Vec3,
+    radius: f32,
+) -> Option<f32> {
+    let to_center = Vec3::new(
+        center.x - origin.x,
+        center.y - origin.y,
+        center.z - origin.z,
+    );
+    let along = to_center.x * direction.x + to_center.y * direction.y + to_center.z * direction.z;
+    if along < 0.0 {
+        return None;
+    }
+
+    let squared_distance =
+        to_center.x * to_center.x + to_center.y * to_center.y + to_center.z * to_center.z
+            - along * along;
+    if squared_distance > radius * radius {
+        return None;
+    }
+
+    Some(along - (radius * radius - squared_distance).sqrt())
+}
+
+/// Distance along the ray from `origin` in the normalized `direction` to an upright capsule
+/// standing on `base`, or `None` if the ray misses it. `height` includes both caps.
+pub fn ray_capsule_distance(
+    origin: Vec3,
+    direction: Vec3,
+    base: Vec3,
+    radius: f32,
+    height: f32,
+) -> Option<f32> {
+    let bottom = Vec3::new(base.x, base.y + radius, base.z);
+    let top = Vec3::new(base.x, base.y + (height - radius).max(radius), base.z);
+
+    // Side of the cylinder between the two caps, intersected on the horizontal plane
+    let side = {
+        let (offset_x, offset_z) = (origin.x - base.x, origin.z - base.z);
+        let a = direction.x * direction.x + direction.z * direction.z;
+        let b = offset_x * direction.x + offset_z * direction.z;
+        let c = offset_x * offset_x + offset_z * offset_z - radius * radius;
+        let discriminant = b * b - a * c;
+        match a > f32::EPSILON && discriminant >= 0.0 {
+            true => Some((-b - discriminant.sqrt()) / a),
+            false => None,
+        }
+    }
+    .filter(|distance| *distance >= 0.0)
+    .filter(|distance| {
+        let y = origin.y + direction.y * distance;
+        y >= bottom.y && y <= top.y
+    });
+
+    [
+        side,
+        ray_sphere_distance(origin, direction, bottom, radius),
+        ray_sphere_distance(origin, direction, top, radius),
+    ]
+    .into_iter()
+    .flatten()
+    .min_by(|a, b| a.total_cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forward() -> Vec3 {
+        Vec3::new(1.0, 0.0, 0.0)
+    }
+
+    fn assert_close(actual: Option<f32>, expected: f32) {
+        let actual = actual.expect("expected a hit");
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn ray_sphere_distance_hits_the_near_side() {
+        let distance = ray_sphere_distance(Vec3::ZERO, forward(), Vec3::new(5.0, 0.0, 0.0), 1.0);
+        assert_close(distance, 4.0);
+    }
+
+    #[test]
+    fn ray_sphere_distance_grazes_off_center() {
+        let distance = ray_sphere_distance(Vec3::ZERO, forward(), Vec3::new(5.0, 0.6, 0.0), 1.0);
+        assert_close(distance, 4.2);
+    }
+
+    #[test]
+    fn ray_sphere_distance_misses() {
+        let center = Vec3::new(5.0, 3.0, 0.0);
+        assert_eq!(
+            ray_sphere_distance(Vec3::ZERO, forward(), center, 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn ray_sphere_distance_ignores_spheres_behind() {
+        let center = Vec3::new(-5.0, 0.0, 0.0);
+        assert_eq!(
+            ray_sphere_distance(Vec3::ZERO, forward(), center, 1.0),
+            None
+        );
+    }
+
+    #[test]
+    fn ray_capsule_distance_hits_the_side() {
+        let origin = Vec3::new(0.0, 1.0, 0.0);
+        let distance = ray_capsule_distance(origin, forward(), Vec3::new(5.0, 0.0, 0.0), 0.5, 2.0);
+        assert_close(distance, 4.5);
+    }
+
+    #[test]
+    fn ray_capsule_distance_hits_the_top_cap() {
+        let origin = Vec3::new(0.0, 1.8, 0.0);
+        let distance = ray_capsule_distance(origin, forward(), Vec3::new(5.0, 0.0, 0.0), 0.5, 2.0);
+        assert_close(distance, 4.6);
+
+        let down = Vec3::new(0.0, -1.0, 0.0);
+        let origin = Vec3::new(5.0, 10.0, 0.0);
+        let distance = ray_capsule_distance(origin, down, Vec3::new(5.0, 0.0, 0.0), 0.5, 2.0);
+        assert_close(distance, 8.0);
+    }
+
+    #[test]
+    fn ray_capsule_distance_misses_above_the_capsule() {
+        let origin = Vec3::new(0.0, 2.5, 0.0);
+        let base = Vec3::new(5.0, 0.0, 0.0);
+        assert_eq!(
+            ray_capsule_distance(origin, forward(), base, 0.5, 2.0),
+            None
+        );
+    }
+}
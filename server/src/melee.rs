@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+use spacetime_engine::{
+    behavior::Status,
+    navigation::NavigationAgent,
+    utils::{Entity, WorldEntity},
+};
+use spacetimedb::{ReducerContext, SpacetimeType, TimeDuration, Timestamp};
+
+use crate::{blackboard::ATTACK_COOLDOWN_TIMER, enemy::Enemy};
+
+/// Reach, damage and timings of a melee attack.
+pub struct MeleeAttackProfile {
+    pub range: f32,
+    pub damage: f32,
+    pub wind_up_micros: i64,
+    pub strike_micros: i64,
+    pub recovery_micros: i64,
+    pub cooldown_micros: i64,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeleeAttackPhase {
+    Idle,
+    WindUp,
+    Strike,
+    Recovery,
+}
+
+pub enum MeleeAttackEvent {
+    None,
+    /// The attack reached its strike moment, damage should be applied if the target is in range.
+    Strike,
+    Finished,
+}
+
+/// Melee attack state machine. The phase timestamps are set when the attack starts
+/// so the client can animate against them.
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct MeleeAttack {
+    pub phase: MeleeAttackPhase,
+    pub started_at: Timestamp,
+    pub strike_at: Timestamp,
+    pub recovery_at: Timestamp,
+    pub ends_at: Timestamp,
+}
+
+impl MeleeAttack {
+    pub fn new(ctx: &ReducerContext) -> Self {
+        Self {
+            phase: MeleeAttackPhase::Idle,
+            started_at: ctx.timestamp,
+            strike_at: ctx.timestamp,
+            recovery_at: ctx.timestamp,
+            ends_at: ctx.timestamp,
+        }
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        self.phase != MeleeAttackPhase::Idle
+    }
+
+    pub fn start(&mut self, ctx: &ReducerContext, profile: &MeleeAttackProfile) {
+        self.start_at(ctx.timestamp, profile);
+    }
+
+    fn start_at(&mut self, now: Timestamp, profile: &MeleeAttackProfile) {
+        self.phase = MeleeAttackPhase::WindUp;
+        self.started_at = now;
+        self.strike_at = self.started_at + TimeDuration::from_micros(profile.wind_up_micros);
+        self.recovery_at = self.strike_at + TimeDuration::from_micros(profile.strike_micros);
+        self.ends_at = self.recovery_at + TimeDuration::from_micros(profile.recovery_micros);
+    }
+
+    pub fn cancel(&mut self) {
+        self.phase = MeleeAttackPhase::Idle;
+    }
+
+    /// Moves to the next phase once the current one is over. Every phase is
+    /// entered at least once, even if a tick spans several of them.
+    pub fn advance(&mut self, ctx: &ReducerContext) -> MeleeAttackEvent {
+        self.advance_at(ctx.timestamp)
+    }
+
+    fn advance_at(&mut self, now: Timestamp) -> MeleeAttackEvent {
+        match self.phase {
+            MeleeAttackPhase::WindUp if now >= self.strike_at => {
+                self.phase = MeleeAttackPhase::Strike;
+                MeleeAttackEvent::Strike
+            }
+            MeleeAttackPhase::Strike if now >= self.recovery_at => {
+                self.phase = MeleeAttackPhase::Recovery;
+                MeleeAttackEvent::None
+            }
+            MeleeAttackPhase::Recovery if now >= self.ends_at => {
+                self.phase = MeleeAttackPhase::Idle;
+                MeleeAttackEvent::Finished
+            }
+            _ => MeleeAttackEvent::None,
+        }
+    }
+}
+
+/// Enemy hitting its target with a single melee attack.
+pub trait MeleeEnemy: Enemy {
+    const MELEE_ATTACK: MeleeAttackProfile;
+
+    fn melee_attack_mut(&mut self) -> &mut MeleeAttack;
+
+    /// Called when the melee attack starts and finishes, for the clients to animate it.
+    fn set_attacking(&mut self, _is_attacking: bool) {}
+}
+
+/// Behavior tree actions of a [`MeleeEnemy`], wrapped by the action enum of its archetype.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum MeleeAction {
+    IsAttacking,
+    Attack,
+}
+
+impl MeleeAction {
+    pub fn run<E: MeleeEnemy>(
+        &self,
+        ctx: &ReducerContext,
+        enemy: &mut E,
+        mut agent: NavigationAgent,
+    ) -> Status {
+        match self {
+            MeleeAction::IsAttacking => match enemy.melee_attack_mut().is_in_progress() {
+                true => Status::Success,
+                false => Status::Failure,
+            },
+            MeleeAction::Attack => {
+                let Some(player) = enemy.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
+
+                if !enemy.melee_attack_mut().is_in_progress() {
+                    if !enemy
+                        .blackboard_mut()
+                        .timer_elapsed(ctx, ATTACK_COOLDOWN_TIMER)
+                    {
+                        return Status::Success;
+                    }
+
+                    if player.position.distance(&agent.position()) > E::MELEE_ATTACK.range {
+                        return Status::Failure;
+                    }
+
+                    // Stand still while winding up so the attack can be dodged by moving away
+                    agent.set_paused(true);
+                    agent.update(ctx);
+
+                    enemy.melee_attack_mut().start(ctx, &E::MELEE_ATTACK);
+                    enemy.set_attacking(true);
+                    enemy.clone().update(ctx);
+                    return Status::Success;
+                }
+
+                match enemy.melee_attack_mut().advance(ctx) {
+                    MeleeAttackEvent::Strike => {
+                        if player.position.distance(&agent.position()) <= E::MELEE_ATTACK.range {
+                            player.damage(ctx, E::MELEE_ATTACK.damage);
+                        }
+                    }
+                    MeleeAttackEvent::Finished => {
+                        agent.set_paused(false);
+                        agent.update(ctx);
+
+                        enemy.set_attacking(false);
+                        enemy.blackboard_mut().set_timer(
+                            ATTACK_COOLDOWN_TIMER,
+                            ctx.timestamp
+                                + TimeDuration::from_micros(E::MELEE_ATTACK.cooldown_micros),
+                        );
+                    }
+                    MeleeAttackEvent::None => {}
+                }
+                enemy.clone().update(ctx);
+
+                Status::Success
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE: MeleeAttackProfile = MeleeAttackProfile {
+        range: 1.0,
+        damage: 10.0,
+        wind_up_micros: 600_000,
+        strike_micros: 200_000,
+        recovery_micros: 400_000,
+        cooldown_micros: 500_000,
+    };
+
+    fn at(micros: i64) -> Timestamp {
+        Timestamp::UNIX_EPOCH + TimeDuration::from_micros(micros)
+    }
+
+    fn started_attack() -> MeleeAttack {
+        let mut attack = MeleeAttack {
+            phase: MeleeAttackPhase::Idle,
+            started_at: at(0),
+            strike_at: at(0),
+            recovery_at: at(0),
+            ends_at: at(0),
+        };
+        attack.start_at(at(0), &PROFILE);
+        attack
+    }
+
+    #[test]
+    fn start_schedules_every_phase() {
+        let attack = started_attack();
+        assert!(attack.is_in_progress());
+        assert_eq!(attack.phase, MeleeAttackPhase::WindUp);
+        assert_eq!(attack.strike_at, at(600_000));
+        assert_eq!(attack.recovery_at, at(800_000));
+        assert_eq!(attack.ends_at, at(1_200_000));
+    }
+
+    #[test]
+    fn phases_advance_in_order() {
+        let mut attack = started_attack();
+
+        assert!(matches!(
+            attack.advance_at(at(100_000)),
+            MeleeAttackEvent::None
+        ));
+        assert_eq!(attack.phase, MeleeAttackPhase::WindUp);
+
+        assert!(matches!(
+            attack.advance_at(at(600_000)),
+            MeleeAttackEvent::Strike
+        ));
+        assert_eq!(attack.phase, MeleeAttackPhase::Strike);
+
+        assert!(matches!(
+            attack.advance_at(at(800_000)),
+            MeleeAttackEvent::None
+        ));
+        assert_eq!(attack.phase, MeleeAttackPhase::Recovery);
+
+        assert!(matches!(
+            attack.advance_at(at(1_200_000)),
+            MeleeAttackEvent::Finished
+        ));
+        assert_eq!(attack.phase, MeleeAttackPhase::Idle);
+        assert!(!attack.is_in_progress());
+    }
+
+    #[test]
+    fn a_late_tick_still_enters_every_phase() {
+        let mut attack = started_attack();
+        let late = at(5_000_000);
+
+        assert!(matches!(attack.advance_at(late), MeleeAttackEvent::Strike));
+        assert!(matches!(attack.advance_at(late), MeleeAttackEvent::None));
+        assert!(matches!(
+            attack.advance_at(late),
+            MeleeAttackEvent::Finished
+        ));
+        assert!(matches!(attack.advance_at(late), MeleeAttackEvent::None));
+    }
+
+    #[test]
+    fn cancel_goes_back_to_idle() {
+        let mut attack = started_attack();
+        attack.cancel();
+        assert!(!attack.is_in_progress());
+        assert!(matches!(
+            attack.advance_at(at(600_000)),
+            MeleeAttackEvent::None
+        ));
+    }
+}
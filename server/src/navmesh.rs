@@ -0,0 +1,56 @@
+use log::info;
+use spacetime_engine::{
+    math::Vec3,
+    navigation::{import_external_navmesh, ExternalNavMesh},
+};
+use spacetimedb::{ReducerContext, Table};
+
+use crate::doors::{door, Door};
+
+/// Navmesh exported from the level, with every door open.
+fn level_navmesh() -> ExternalNavMesh {
+    let file = include_str!("../../client/navmesh_export.json");
+    info!("navmesh export file size: {} bytes", file.len());
+    serde_json::from_str::<ExternalNavMesh>(file).expect("Failed to parse navmesh JSON")
+}
+
+fn polygon_center(vertices: &[Vec3], polygon: &[u64]) -> Vec3 {
+    let count = polygon.len().max(1) as f32;
+    let (x, y, z) = polygon
+        .iter()
+        .map(|index| vertices[*index as usize])
+        .fold((0.0, 0.0, 0.0), |(x, y, z), vertex| {
+            (x + vertex.x, y + vertex.y, z + vertex.z)
+        });
+    Vec3::new(x / count, y / count, z / count)
+}
+
+/// Uploads the level navmesh to the world, without the polygons blocked by closed doors.
+/// Importing a navmesh replaces the one of the world, so this runs again whenever a door
+/// opens or closes and agents can only path through the doors players bought.
+pub fn rebuild_navmesh(ctx: &ReducerContext, world_id: u64) {
+    let mut navmesh = level_navmesh();
+    let closed_doors: Vec<Door> = ctx.db.door().iter().filter(|door| !door.is_open).collect();
+
+    let polygons = std::mem::take(&mut navmesh.polygons);
+    let polygon_type_indices = std::mem::take(&mut navmesh.polygon_type_indices);
+    let (polygons, polygon_type_indices) = polygons
+        .into_iter()
+        .zip(polygon_type_indices)
+        .filter(|(polygon, _)| {
+            let center = polygon_center(&navmesh.vertices, polygon);
+            !closed_doors.iter().any(|door| door.blocks(center))
+        })
+        .unzip();
+    navmesh.polygons = polygons;
+    navmesh.polygon_type_indices = polygon_type_indices;
+
+    info!(
+        "Uploading navmesh with {} vertices and {} polygons for world {}, {} doors closed",
+        navmesh.vertices.len(),
+        navmesh.polygons.len(),
+        world_id,
+        closed_doors.len()
+    );
+    import_external_navmesh(ctx, world_id, navmesh);
+}
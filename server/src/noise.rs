@@ -0,0 +1,170 @@
+use spacetime_engine::math::Vec3;
+use spacetimedb::{
+    reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+};
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoiseKind {
+    Gunshot,
+    Sprint,
+    Explosion,
+}
+
+impl NoiseKind {
+    /// Radius (meters), loudness and lifetime (micros) of each kind of noise.
+    fn profile(self) -> (f32, f32, i64) {
+        match self {
+            NoiseKind::Gunshot => (30.0, 1.0, 2_000_000),
+            NoiseKind::Sprint => (8.0, 0.3, 500_000),
+            NoiseKind::Explosion => (40.0, 1.5, 3_000_000),
+        }
+    }
+}
+
+/// Noise enemies can hear, deleted once it expires.
+#[table(name = noise_event, public, scheduled(expire_noise_event))]
+pub struct NoiseEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub kind: NoiseKind,
+    pub position: Vec3,
+    pub radius: f32,
+    pub loudness: f32,
+    /// Player who made the noise, enemies hearing it go after them.
+    pub source_player: Option<u64>,
+    pub expires_at: Timestamp,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl NoiseEvent {
+    pub fn emit(
+        ctx: &ReducerContext,
+        kind: NoiseKind,
+        position: Vec3,
+        source_player: Option<u64>,
+    ) -> Self {
+        let (radius, loudness, duration_micros) = kind.profile();
+        let expires_at = ctx.timestamp + TimeDuration::from_micros(duration_micros);
+        let noise = NoiseEvent {
+            id: 0,
+            kind,
+            position,
+            radius,
+            loudness,
+            source_player,
+            expires_at,
+            scheduled_at: ScheduleAt::Time(expires_at),
+        };
+
+        // Continuous noises like sprinting move the existing event instead of piling up new ones
+        let existing = source_player.and_then(|player_id| {
+            ctx.db
+                .noise_event()
+                .iter()
+                .find(|noise| noise.kind == kind && noise.source_player == Some(player_id))
+        });
+        match existing {
+            Some(existing) => ctx.db.noise_event().id().update(NoiseEvent {
+                id: existing.id,
+                ..noise
+            }),
+            None => ctx.db.noise_event().insert(noise),
+        }
+    }
+
+    /// Loudest noise that can be heard from `position`.
+    pub fn loudest_heard(ctx: &ReducerContext, position: Vec3) -> Option<Self> {
+        Self::loudest_among(ctx.db.noise_event().iter(), ctx.timestamp, position)
+    }
+
+    /// Loudest of `noises` that can still be heard from `position` at `now`.
+    fn loudest_among(
+        noises: impl IntoIterator<Item = Self>,
+        now: Timestamp,
+        position: Vec3,
+    ) -> Option<Self> {
+        noises
+            .into_iter()
+            .filter(|noise| {
+                now < noise.expires_at && noise.position.distance(&position) <= noise.radius
+            })
+            .max_by(|a, b| a.loudness.total_cmp(&b.loudness))
+    }
+
+    pub fn clear(ctx: &ReducerContext) {
+        for noise in ctx.db.noise_event().iter() {
+            ctx.db.noise_event().id().delete(noise.id);
+        }
+    }
+}
+
+#[reducer]
+fn expire_noise_event(ctx: &ReducerContext, noise: NoiseEvent) {
+    // The noise may have been extended since this expiry was scheduled
+    let Some(noise) = ctx.db.noise_event().id().find(noise.id) else {
+        return;
+    };
+    if ctx.timestamp >= noise.expires_at {
+        ctx.db.noise_event().id().delete(noise.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(micros: i64) -> Timestamp {
+        Timestamp::UNIX_EPOCH + TimeDuration::from_micros(micros)
+    }
+
+    fn noise(id: u64, kind: NoiseKind, position: Vec3, expires_at: Timestamp) -> NoiseEvent {
+        let (radius, loudness, _) = kind.profile();
+        NoiseEvent {
+            id,
+            kind,
+            position,
+            radius,
+            loudness,
+            source_player: None,
+            expires_at,
+            scheduled_at: ScheduleAt::Time(expires_at),
+        }
+    }
+
+    fn loudest_id(noises: Vec<NoiseEvent>, now: Timestamp, position: Vec3) -> Option<u64> {
+        NoiseEvent::loudest_among(noises, now, position).map(|noise| noise.id)
+    }
+
+    #[test]
+    fn loudest_noise_in_range_wins() {
+        let noises = vec![
+            noise(1, NoiseKind::Sprint, Vec3::ZERO, at(1_000)),
+            noise(2, NoiseKind::Gunshot, Vec3::new(10.0, 0.0, 0.0), at(1_000)),
+        ];
+        assert_eq!(loudest_id(noises, at(0), Vec3::ZERO), Some(2));
+    }
+
+    #[test]
+    fn noises_out_of_range_are_not_heard() {
+        let noises = vec![
+            noise(1, NoiseKind::Sprint, Vec3::ZERO, at(1_000)),
+            noise(2, NoiseKind::Gunshot, Vec3::new(100.0, 0.0, 0.0), at(1_000)),
+        ];
+        assert_eq!(loudest_id(noises, at(0), Vec3::ZERO), Some(1));
+    }
+
+    #[test]
+    fn expired_noises_are_not_heard() {
+        let noises = vec![
+            noise(1, NoiseKind::Sprint, Vec3::ZERO, at(1_000)),
+            noise(2, NoiseKind::Explosion, Vec3::ZERO, at(500)),
+        ];
+        assert_eq!(loudest_id(noises, at(500), Vec3::ZERO), Some(1));
+    }
+
+    #[test]
+    fn nothing_heard_without_noises() {
+        assert_eq!(loudest_id(Vec::new(), at(0), Vec3::ZERO), None);
+    }
+}
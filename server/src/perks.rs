@@ -0,0 +1,53 @@
+use spacetime_engine::utils::Entity;
+use spacetimedb::{table, ReducerContext, SpacetimeType, Table};
+
+use crate::tables::player::Player;
+
+/// Health added on top of the current health when buying [`PerkKind::Juggernaut`].
+const JUGGERNAUT_BONUS_HEALTH: f32 = 50.0;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PerkKind {
+    /// Extra health.
+    Juggernaut,
+    /// Faster reloads.
+    SleightOfHand,
+}
+
+/// Perk bought by a player, kept until they leave.
+#[table(name = player_perk, public)]
+pub struct PlayerPerk {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: u64,
+    pub kind: PerkKind,
+}
+
+impl PlayerPerk {
+    pub fn give(ctx: &ReducerContext, mut player: Player, kind: PerkKind) -> Self {
+        if kind == PerkKind::Juggernaut {
+            player.health += JUGGERNAUT_BONUS_HEALTH;
+            player = player.update(ctx);
+        }
+
+        ctx.db.player_perk().insert(PlayerPerk {
+            id: 0,
+            player_id: player.id,
+            kind,
+        })
+    }
+
+    pub fn has(ctx: &ReducerContext, player_id: u64, kind: PerkKind) -> bool {
+        ctx.db
+            .player_perk()
+            .player_id()
+            .filter(player_id)
+            .any(|perk| perk.kind == kind)
+    }
+
+    pub fn clear_for(ctx: &ReducerContext, player_id: u64) {
+        ctx.db.player_perk().player_id().delete(player_id);
+    }
+}
@@ -5,11 +5,20 @@ use spacetime_engine::{
 };
 use spacetimedb::{reducer, ReducerContext};
 
-use crate::tables::player::{player, Player};
+use crate::{
+    noise::{NoiseEvent, NoiseKind},
+    tables::player::{player, Player},
+    weapons::{PlayerWeapon, WeaponKind},
+};
+
+/// Players moving faster than this (m/s) on the ground are sprinting and can be heard.
+/// Well above the walk speed of 5 m/s so walking players stay quiet.
+const SPRINT_SPEED: f32 = 7.5;
 
 #[reducer]
 fn player_ready(ctx: &ReducerContext) {
-    Player::create(ctx);
+    let player = Player::create(ctx);
+    PlayerWeapon::give(ctx, player.id, WeaponKind::Pistol);
 }
 
 #[reducer]
@@ -17,8 +26,35 @@ fn player_update_position(ctx: &ReducerContext, new_position: Vec3) {
     let mut player = ctx.db.player().identity().find(ctx.sender).unwrap();
     let mut rb = RigidBody::find(ctx, player.rigid_body_id).unwrap();
 
+    // The first position only places the player, it isn't a move
+    player.velocity = match player.position_updated_at {
+        Some(updated_at) => {
+            let elapsed_micros = ctx.timestamp.to_micros_since_unix_epoch()
+                - updated_at.to_micros_since_unix_epoch();
+            match elapsed_micros > 0 {
+                true => {
+                    let elapsed = elapsed_micros as f32 / 1_000_000.0;
+                    Vec3::new(
+                        (new_position.x - player.position.x) / elapsed,
+                        (new_position.y - player.position.y) / elapsed,
+                        (new_position.z - player.position.z) / elapsed,
+                    )
+                }
+                false => player.velocity,
+            }
+        }
+        None => Vec3::ZERO,
+    };
+
     player.position = new_position;
-    player.update(ctx);
+    player.position_updated_at = Some(ctx.timestamp);
+    let player = player.update(ctx);
+
+    // Jumping and falling don't make a player sprint
+    let ground_speed = player.velocity.x.hypot(player.velocity.z);
+    if ground_speed > SPRINT_SPEED {
+        NoiseEvent::emit(ctx, NoiseKind::Sprint, player.position, Some(player.id));
+    }
 
     rb.position = new_position;
     rb.update(ctx);
@@ -0,0 +1,138 @@
+use spacetime_engine::{math::Vec3, utils::Entity};
+use spacetimedb::{
+    rand::seq::SliceRandom, reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table,
+    TimeDuration, Timestamp,
+};
+
+use crate::{
+    brute_zombie::BruteZombie,
+    constants::POWER_UP_DROP_CHANCE,
+    enemy::kill_all,
+    exploder_zombie::ExploderZombie,
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
+    spitter_zombie::SpitterZombie,
+    tables::{player::Player, zombie::Zombie},
+    weapons::PlayerWeapon,
+    zones::{Zone, ZoneKind},
+};
+
+const POWER_UP_DURATION_MICROS: i64 = 30_000_000;
+/// Points earned by the player picking up a nuke.
+const NUKE_POINTS: u32 = 400;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerUpKind {
+    /// Refills the ammo of every player.
+    MaxAmmo,
+    /// Player hits kill in one shot.
+    InstaKill,
+    /// Players earn twice the points.
+    DoublePoints,
+    /// Kills every enemy but the boss.
+    Nuke,
+}
+
+impl PowerUpKind {
+    const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::MaxAmmo,
+        PowerUpKind::InstaKill,
+        PowerUpKind::DoublePoints,
+        PowerUpKind::Nuke,
+    ];
+
+    /// Drops a random power-up at `position`, with a [`POWER_UP_DROP_CHANCE`] chance.
+    pub fn roll_drop(ctx: &ReducerContext, position: Vec3) -> Option<Zone> {
+        if ctx.random::<f32>() > POWER_UP_DROP_CHANCE {
+            return None;
+        }
+
+        let kind = *Self::ALL.choose(&mut ctx.rng())?;
+        Some(Zone::create(ctx, ZoneKind::PowerUp(kind), position))
+    }
+
+    /// Applies the power-up picked up by `player`.
+    pub fn apply(self, ctx: &ReducerContext, player: Player) {
+        log::info!("Player#{} picked up {:?}", player.id, self);
+        match self {
+            PowerUpKind::MaxAmmo => {
+                for player in Player::iter(ctx) {
+                    PlayerWeapon::refill_all(ctx, player.id);
+                }
+            }
+            PowerUpKind::InstaKill | PowerUpKind::DoublePoints => {
+                ActivePowerUp::activate(ctx, self);
+            }
+            PowerUpKind::Nuke => {
+                kill_all::<Zombie>(ctx);
+                kill_all::<SpitterZombie>(ctx);
+                kill_all::<RunnerZombie>(ctx);
+                kill_all::<BruteZombie>(ctx);
+                kill_all::<ExploderZombie>(ctx);
+                kill_all::<ScreamerZombie>(ctx);
+                player.add_points(ctx, NUKE_POINTS);
+            }
+        }
+    }
+}
+
+/// Timed power-up affecting every player, deleted once it ends.
+#[table(name = active_power_up, public, scheduled(expire_power_up))]
+pub struct ActivePowerUp {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub kind: PowerUpKind,
+    pub ends_at: Timestamp,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl ActivePowerUp {
+    /// Starts the power-up, or restarts its timer if it is already active.
+    pub fn activate(ctx: &ReducerContext, kind: PowerUpKind) -> Self {
+        let ends_at = ctx.timestamp + TimeDuration::from_micros(POWER_UP_DURATION_MICROS);
+        let power_up = ActivePowerUp {
+            id: 0,
+            kind,
+            ends_at,
+            scheduled_at: ScheduleAt::Time(ends_at),
+        };
+
+        match ctx
+            .db
+            .active_power_up()
+            .iter()
+            .find(|power_up| power_up.kind == kind)
+        {
+            Some(existing) => ctx.db.active_power_up().id().update(ActivePowerUp {
+                id: existing.id,
+                ..power_up
+            }),
+            None => ctx.db.active_power_up().insert(power_up),
+        }
+    }
+
+    pub fn is_active(ctx: &ReducerContext, kind: PowerUpKind) -> bool {
+        ctx.db
+            .active_power_up()
+            .iter()
+            .any(|power_up| power_up.kind == kind && ctx.timestamp < power_up.ends_at)
+    }
+
+    pub fn clear(ctx: &ReducerContext) {
+        for power_up in ctx.db.active_power_up().iter() {
+            ctx.db.active_power_up().id().delete(power_up.id);
+        }
+    }
+}
+
+#[reducer]
+fn expire_power_up(ctx: &ReducerContext, power_up: ActivePowerUp) {
+    // The power-up may have been picked up again since this expiry was scheduled
+    let Some(power_up) = ctx.db.active_power_up().id().find(power_up.id) else {
+        return;
+    };
+    if ctx.timestamp >= power_up.ends_at {
+        ctx.db.active_power_up().id().delete(power_up.id);
+    }
+}
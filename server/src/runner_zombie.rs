@@ -0,0 +1,281 @@
+use serde::{Deserialize, Serialize};
+use spacetime_engine::{
+    behavior::{Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
+    math::Vec3,
+    navigation::{DestinationReachedCondition, NavigationAgent, NavigationAgentId},
+    utils::{Entity, WorldEntity},
+    world::World,
+};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration};
+
+use crate::{
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    barricades::Barricade,
+    behavior_debug::{trace_action, tree_leaves},
+    blackboard::{Blackboard, ATTACK_COOLDOWN_TIMER},
+    constants::{RUNNER_ZOMBIE_ARCHETYPE_ID, RUNNER_ZOMBIE_COLLIDER_ID},
+    enemy::{impl_enemy_entity, tick_enemies, Enemy},
+    enemy_actions::EnemyAction,
+    enemy_body::{BodyCapsule, EnemyBody},
+    melee::{MeleeAttack, MeleeAttackEvent, MeleeAttackProfile},
+    stuck::StuckTracker,
+    zombies_spawner::ZombieSpawnPoint,
+};
+
+const RUNNER_ZOMBIE_HEALTH: f32 = 50.0;
+const RUNNER_ZOMBIE_KILL_POINTS: u32 = 70;
+const RUNNER_ZOMBIE_HEAD_HEIGHT: f32 = 1.5;
+
+/// The runner leaps at its target from a few meters away instead of walking up to it.
+const RUNNER_ZOMBIE_LUNGE: MeleeAttackProfile = MeleeAttackProfile {
+    range: 3.0,
+    damage: 15.0,
+    wind_up_micros: 300_000,
+    strike_micros: 200_000,
+    recovery_micros: 700_000,
+    cooldown_micros: 1_500_000,
+};
+
+pub type RunnerZombieId = u64;
+
+#[table(name = runner_zombie, public)]
+#[derive(Clone)]
+pub struct RunnerZombie {
+    #[primary_key]
+    #[auto_inc]
+    pub id: RunnerZombieId,
+    #[index(btree)]
+    pub navigation_agent_id: NavigationAgentId,
+    pub position: Vec3,
+    pub health: f32,
+    pub target_player: Option<u64>,
+    pub lunge: MeleeAttack,
+    pub barricade_id: Option<u64>,
+    pub blackboard: Blackboard,
+    pub stuck: StuckTracker,
+    pub body: EnemyBody,
+}
+
+impl_enemy_entity!(RunnerZombie, runner_zombie);
+
+impl Enemy for RunnerZombie {
+    const ARCHETYPE_ID: ArchetypeId = RUNNER_ZOMBIE_ARCHETYPE_ID;
+    const BODY: BodyCapsule = BodyCapsule {
+        radius: 0.35,
+        height: 1.7,
+    };
+
+    fn kill_points(&self) -> u32 {
+        RUNNER_ZOMBIE_KILL_POINTS
+    }
+
+    fn set_target(&mut self, player_id: u64) {
+        self.target_player = Some(player_id);
+    }
+
+    fn clear_target(&mut self) {
+        self.target_player = None;
+        self.lunge.cancel();
+    }
+
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId {
+        NavigationAgent::builder()
+            .desired_speed(6.0)
+            .max_speed(8.0)
+            .radius(0.3)
+            .target_reached_condition(DestinationReachedCondition::Distance(Some(1.5)))
+            .position(position)
+            .build()
+            .insert(ctx)
+            .id()
+    }
+}
+
+impl RunnerZombie {
+    pub fn create(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint) -> Self {
+        let position = spawn_point.position;
+        let runner = RunnerZombie {
+            id: 0,
+            navigation_agent_id: Self::create_navigation_agent(ctx, position),
+            position,
+            health: RUNNER_ZOMBIE_HEALTH,
+            target_player: None,
+            lunge: MeleeAttack::new(ctx),
+            barricade_id: Barricade::find_for_spawn_point(ctx, spawn_point.id)
+                .map(|barricade| barricade.id),
+            blackboard: Blackboard::default(),
+            stuck: StuckTracker::new(position),
+            body: EnemyBody::create(
+                ctx,
+                RUNNER_ZOMBIE_COLLIDER_ID,
+                RUNNER_ZOMBIE_HEAD_HEIGHT,
+                position,
+            ),
+        }
+        .insert(ctx);
+        runner
+            .body
+            .register(ctx, RUNNER_ZOMBIE_ARCHETYPE_ID, runner.id);
+        runner
+    }
+}
+
+#[table(name = runner_zombie_update_tick, scheduled(tick_runner_zombie))]
+pub struct RunnerZombieUpdateTick {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl RunnerZombieUpdateTick {
+    pub fn schedule(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .runner_zombie_update_tick()
+            .insert(RunnerZombieUpdateTick {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(250_000)),
+            })
+    }
+}
+
+#[reducer]
+fn tick_runner_zombie(ctx: &ReducerContext, tick: RunnerZombieUpdateTick) -> Result<(), String> {
+    tick_enemies::<RunnerZombie, RunnerZombieAction>(ctx, tick.scheduled_at)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum RunnerZombieAction {
+    IsLunging,
+    IsInLungeRange,
+    Lunge,
+    Enemy(EnemyAction),
+}
+
+impl BehaviorExecutor<RunnerZombieAction> for RunnerZombie {
+    fn run_action(
+        &mut self,
+        ctx: &ReducerContext,
+        _world: &World,
+        _dt: f32,
+        action: &RunnerZombieAction,
+    ) -> Status {
+        let status = self.execute_action(ctx, action);
+        trace_action(ctx, RUNNER_ZOMBIE_ARCHETYPE_ID, self.id, action, &status);
+        status
+    }
+}
+
+impl RunnerZombie {
+    fn execute_action(&mut self, ctx: &ReducerContext, action: &RunnerZombieAction) -> Status {
+        let mut agent = NavigationAgent::find(ctx, self.navigation_agent_id)
+            .expect("NavigationAgent not found");
+        match action {
+            RunnerZombieAction::IsLunging => match self.lunge.is_in_progress() {
+                true => Status::Success,
+                false => Status::Failure,
+            },
+            RunnerZombieAction::IsInLungeRange => {
+                let Some(player) = self.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
+
+                match self.blackboard.timer_elapsed(ctx, ATTACK_COOLDOWN_TIMER)
+                    && player.position.distance(&agent.position()) <= RUNNER_ZOMBIE_LUNGE.range
+                {
+                    true => Status::Success,
+                    false => Status::Failure,
+                }
+            }
+            RunnerZombieAction::Lunge => {
+                let Some(player) = self.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
+
+                if !self.lunge.is_in_progress() {
+                    // Crouch before leaping so the lunge can be seen coming
+                    agent.set_paused(true);
+                    agent.update(ctx);
+
+                    self.lunge.start(ctx, &RUNNER_ZOMBIE_LUNGE);
+                    self.clone().update(ctx);
+                    return Status::Success;
+                }
+
+                match self.lunge.advance(ctx) {
+                    MeleeAttackEvent::Strike => {
+                        let in_range = player.position.distance(&agent.position())
+                            <= RUNNER_ZOMBIE_LUNGE.range;
+
+                        // Leap towards where the player stands at the strike moment
+                        agent
+                            .set_destination(Some(player.position))
+                            .set_paused(false);
+                        agent.update(ctx);
+
+                        if in_range {
+                            player.damage(ctx, RUNNER_ZOMBIE_LUNGE.damage);
+                        }
+                    }
+                    MeleeAttackEvent::Finished => {
+                        self.blackboard.set_timer(
+                            ATTACK_COOLDOWN_TIMER,
+                            ctx.timestamp
+                                + TimeDuration::from_micros(RUNNER_ZOMBIE_LUNGE.cooldown_micros),
+                        );
+                    }
+                    MeleeAttackEvent::None => {}
+                }
+                self.clone().update(ctx);
+
+                Status::Success
+            }
+            RunnerZombieAction::Enemy(action) => action.run(ctx, self, agent),
+        }
+    }
+}
+
+pub fn create_runner_zombie_behavior_tree(ctx: &ReducerContext) {
+    let bt = Select(vec![
+        // Tear down the barricade of our spawn point before getting in
+        Sequence(vec![
+            Action(RunnerZombieAction::Enemy(EnemyAction::IsBehindBarricade)),
+            Action(RunnerZombieAction::Enemy(EnemyAction::AttackObstacle)),
+        ]),
+        // Finish a lunge once it started
+        Sequence(vec![
+            Action(RunnerZombieAction::IsLunging),
+            Action(RunnerZombieAction::Lunge),
+        ]),
+        // Lunge at the target as soon as it is close enough
+        Sequence(vec![
+            Action(RunnerZombieAction::IsInLungeRange),
+            Action(RunnerZombieAction::Lunge),
+        ]),
+        // If we are stuck, repath, then side-step and finally teleport to the nearest spawn
+        Sequence(vec![
+            Action(RunnerZombieAction::Enemy(EnemyAction::IsStuck)),
+            Select(vec![
+                Action(RunnerZombieAction::Enemy(EnemyAction::Repath)),
+                Action(RunnerZombieAction::Enemy(EnemyAction::SideStep)),
+                Action(RunnerZombieAction::Enemy(EnemyAction::TeleportToSpawn)),
+            ]),
+        ]),
+        // Let a side-step play out before chasing again
+        Action(RunnerZombieAction::Enemy(EnemyAction::IsRecovering)),
+        // Let a knock back push us before chasing again
+        Action(RunnerZombieAction::Enemy(EnemyAction::IsKnockedBack)),
+        // Keep chasing the target, even while the lunge cools down
+        Action(RunnerZombieAction::Enemy(EnemyAction::Chase)),
+        // Otherwise, target a random player
+        Action(RunnerZombieAction::Enemy(EnemyAction::TargetRandomPlayer)),
+    ]);
+    let leaves = tree_leaves(&bt);
+    let tree_id = BehaviorTree::create(ctx, bt).id;
+    ArchetypeBehaviorTree::register::<RunnerZombieAction>(
+        ctx,
+        RUNNER_ZOMBIE_ARCHETYPE_ID,
+        tree_id,
+        leaves,
+    );
+}
@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+use spacetime_engine::{
+    behavior::{Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
+    math::Vec3,
+    navigation::{DestinationReachedCondition, NavigationAgent, NavigationAgentId},
+    utils::{Entity, WorldEntity},
+    world::World,
+};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::{
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    barricades::Barricade,
+    behavior_debug::{trace_action, tree_leaves},
+    blackboard::Blackboard,
+    brute_zombie::BruteZombie,
+    constants::{SCREAMER_ZOMBIE_ARCHETYPE_ID, SCREAMER_ZOMBIE_COLLIDER_ID},
+    enemy::{alert_enemies_in_radius, impl_enemy_entity, tick_enemies, Enemy},
+    enemy_actions::EnemyAction,
+    enemy_body::{BodyCapsule, EnemyBody},
+    exploder_zombie::ExploderZombie,
+    runner_zombie::RunnerZombie,
+    spitter_zombie::SpitterZombie,
+    stuck::StuckTracker,
+    tables::{player::Player, zombie::Zombie},
+    zombies_spawner::{ZombieSpawnPoint, ZombieSpawnRequest},
+};
+
+/// Fragile so players can take it down before it screams again.
+const SCREAMER_ZOMBIE_HEALTH: f32 = 60.0;
+/// Worth more than its health suggests, it should be killed first.
+const SCREAMER_ZOMBIE_KILL_POINTS: u32 = 150;
+const SCREAMER_ZOMBIE_HEAD_HEIGHT: f32 = 1.7;
+
+const SCREAMER_SIGHT_RANGE: f32 = 12.0;
+const SCREAMER_ALERT_RADIUS: f32 = 25.0;
+const SCREAMER_REINFORCEMENTS: u32 = 4;
+const SCREAMER_SCREAM_MICROS: i64 = 2_000_000;
+const SCREAMER_SCREAM_COOLDOWN_MICROS: i64 = 15_000_000;
+
+const SCREAM_COOLDOWN_TIMER: &str = "scream_cooldown";
+
+pub type ScreamerZombieId = u64;
+
+#[table(name = screamer_zombie, public)]
+#[derive(Clone)]
+pub struct ScreamerZombie {
+    #[primary_key]
+    #[auto_inc]
+    pub id: ScreamerZombieId,
+    #[index(btree)]
+    pub navigation_agent_id: NavigationAgentId,
+    pub position: Vec3,
+    pub health: f32,
+    pub target_player: Option<u64>,
+    /// Set while screaming, the client plays the scream until then.
+    pub screaming_until: Option<Timestamp>,
+    pub barricade_id: Option<u64>,
+    pub blackboard: Blackboard,
+    pub stuck: StuckTracker,
+    pub body: EnemyBody,
+}
+
+impl_enemy_entity!(ScreamerZombie, screamer_zombie);
+
+impl Enemy for ScreamerZombie {
+    const ARCHETYPE_ID: ArchetypeId = SCREAMER_ZOMBIE_ARCHETYPE_ID;
+    const BODY: BodyCapsule = BodyCapsule {
+        radius: 0.4,
+        height: 1.9,
+    };
+
+    fn kill_points(&self) -> u32 {
+        SCREAMER_ZOMBIE_KILL_POINTS
+    }
+
+    fn set_target(&mut self, player_id: u64) {
+        self.target_player = Some(player_id);
+    }
+
+    fn clear_target(&mut self) {
+        self.target_player = None;
+    }
+
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId {
+        NavigationAgent::builder()
+            .desired_speed(2.5)
+            .max_speed(4.0)
+            .radius(0.3)
+            .target_reached_condition(DestinationReachedCondition::Distance(Some(
+                SCREAMER_SIGHT_RANGE / 2.0,
+            )))
+            .position(position)
+            .build()
+            .insert(ctx)
+            .id()
+    }
+}
+
+impl ScreamerZombie {
+    pub fn create(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint) -> Self {
+        let position = spawn_point.position;
+        let screamer = ScreamerZombie {
+            id: 0,
+            navigation_agent_id: Self::create_navigation_agent(ctx, position),
+            position,
+            health: SCREAMER_ZOMBIE_HEALTH,
+            target_player: None,
+            screaming_until: None,
+            barricade_id: Barricade::find_for_spawn_point(ctx, spawn_point.id)
+                .map(|barricade| barricade.id),
+            blackboard: Blackboard::default(),
+            stuck: StuckTracker::new(position),
+            body: EnemyBody::create(
+                ctx,
+                SCREAMER_ZOMBIE_COLLIDER_ID,
+                SCREAMER_ZOMBIE_HEAD_HEIGHT,
+                position,
+            ),
+        }
+        .insert(ctx);
+        screamer
+            .body
+            .register(ctx, SCREAMER_ZOMBIE_ARCHETYPE_ID, screamer.id);
+        screamer
+    }
+
+    /// The screamer follows the players from afar rather than fighting them.
+    fn nearest_visible_player(&self, ctx: &ReducerContext) -> Option<Player> {
+        Player::iter(ctx)
+            .filter(|player| player.position.distance(&self.position) <= SCREAMER_SIGHT_RANGE)
+            .min_by(|a, b| {
+                a.position
+                    .distance(&self.position)
+                    .total_cmp(&b.position.distance(&self.position))
+            })
+    }
+
+    /// Calls the zombies around to `player` and asks the spawner for reinforcements.
+    fn scream(&self, ctx: &ReducerContext, player: &Player) {
+        alert_enemies_in_radius::<Zombie>(ctx, self.position, SCREAMER_ALERT_RADIUS, player);
+        alert_enemies_in_radius::<SpitterZombie>(ctx, self.position, SCREAMER_ALERT_RADIUS, player);
+        alert_enemies_in_radius::<RunnerZombie>(ctx, self.position, SCREAMER_ALERT_RADIUS, player);
+        alert_enemies_in_radius::<BruteZombie>(ctx, self.position, SCREAMER_ALERT_RADIUS, player);
+        alert_enemies_in_radius::<ExploderZombie>(
+            ctx,
+            self.position,
+            SCREAMER_ALERT_RADIUS,
+            player,
+        );
+
+        if let Some(spawn_point) = ZombieSpawnPoint::nearest(ctx, player.position) {
+            ZombieSpawnRequest::create(ctx, spawn_point.id, SCREAMER_REINFORCEMENTS);
+        }
+    }
+}
+
+#[table(name = screamer_zombie_update_tick, scheduled(tick_screamer_zombie))]
+pub struct ScreamerZombieUpdateTick {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl ScreamerZombieUpdateTick {
+    pub fn schedule(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .screamer_zombie_update_tick()
+            .insert(ScreamerZombieUpdateTick {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(250_000)),
+            })
+    }
+}
+
+#[reducer]
+fn tick_screamer_zombie(
+    ctx: &ReducerContext,
+    tick: ScreamerZombieUpdateTick,
+) -> Result<(), String> {
+    tick_enemies::<ScreamerZombie, ScreamerZombieAction>(ctx, tick.scheduled_at)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum ScreamerZombieAction {
+    IsScreaming,
+    CanSeePlayer,
+    Scream,
+    Enemy(EnemyAction),
+}
+
+impl BehaviorExecutor<ScreamerZombieAction> for ScreamerZombie {
+    fn run_action(
+        &mut self,
+        ctx: &ReducerContext,
+        _world: &World,
+        _dt: f32,
+        action: &ScreamerZombieAction,
+    ) -> Status {
+        let status = self.execute_action(ctx, action);
+        trace_action(ctx, SCREAMER_ZOMBIE_ARCHETYPE_ID, self.id, action, &status);
+        status
+    }
+}
+
+impl ScreamerZombie {
+    fn execute_action(&mut self, ctx: &ReducerContext, action: &ScreamerZombieAction) -> Status {
+        let mut agent = NavigationAgent::find(ctx, self.navigation_agent_id)
+            .expect("NavigationAgent not found");
+        match action {
+            ScreamerZombieAction::IsScreaming => match self.screaming_until.is_some() {
+                true => Status::Success,
+                false => Status::Failure,
+            },
+            ScreamerZombieAction::CanSeePlayer => {
+                if !self.blackboard.timer_elapsed(ctx, SCREAM_COOLDOWN_TIMER) {
+                    return Status::Failure;
+                }
+
+                match self.nearest_visible_player(ctx) {
+                    Some(_) => Status::Success,
+                    None => Status::Failure,
+                }
+            }
+            ScreamerZombieAction::Scream => {
+                match self.screaming_until {
+                    Some(until) if ctx.timestamp < until => {}
+                    Some(_) => {
+                        agent.set_paused(false);
+                        agent.update(ctx);
+
+                        self.screaming_until = None;
+                        self.clone().update(ctx);
+                    }
+                    None => {
+                        let Some(player) = self.nearest_visible_player(ctx) else {
+                            return Status::Failure;
+                        };
+
+                        agent.set_paused(true);
+                        agent.update(ctx);
+
+                        self.scream(ctx, &player);
+                        self.target_player = Some(player.id);
+                        self.blackboard.last_seen_position = Some(player.position);
+                        self.screaming_until =
+                            Some(ctx.timestamp + TimeDuration::from_micros(SCREAMER_SCREAM_MICROS));
+                        self.blackboard.set_timer(
+                            SCREAM_COOLDOWN_TIMER,
+                            ctx.timestamp
+                                + TimeDuration::from_micros(SCREAMER_SCREAM_COOLDOWN_MICROS),
+                        );
+                        self.clone().update(ctx);
+                    }
+                }
+                Status::Success
+            }
+            ScreamerZombieAction::Enemy(action) => action.run(ctx, self, agent),
+        }
+    }
+}
+
+pub fn create_screamer_zombie_behavior_tree(ctx: &ReducerContext) {
+    let bt = Select(vec![
+        // Tear down the barricade of our spawn point before getting in
+        Sequence(vec![
+            Action(ScreamerZombieAction::Enemy(EnemyAction::IsBehindBarricade)),
+            Action(ScreamerZombieAction::Enemy(EnemyAction::AttackObstacle)),
+        ]),
+        // Stand still until the scream is over
+        Sequence(vec![
+            Action(ScreamerZombieAction::IsScreaming),
+            Action(ScreamerZombieAction::Scream),
+        ]),
+        // Scream as soon as a player is in sight
+        Sequence(vec![
+            Action(ScreamerZombieAction::CanSeePlayer),
+            Action(ScreamerZombieAction::Scream),
+        ]),
+        // If we are stuck, repath, then side-step and finally teleport to the nearest spawn
+        Sequence(vec![
+            Action(ScreamerZombieAction::Enemy(EnemyAction::IsStuck)),
+            Select(vec![
+                Action(ScreamerZombieAction::Enemy(EnemyAction::Repath)),
+                Action(ScreamerZombieAction::Enemy(EnemyAction::SideStep)),
+                Action(ScreamerZombieAction::Enemy(EnemyAction::TeleportToSpawn)),
+            ]),
+        ]),
+        // Let a side-step play out before following again
+        Action(ScreamerZombieAction::Enemy(EnemyAction::IsRecovering)),
+        // Let a knock back push us before following again
+        Action(ScreamerZombieAction::Enemy(EnemyAction::IsKnockedBack)),
+        // Follow the target from a distance, or pick one if we have none
+        Action(ScreamerZombieAction::Enemy(EnemyAction::Chase)),
+        Action(ScreamerZombieAction::Enemy(EnemyAction::TargetRandomPlayer)),
+    ]);
+    let leaves = tree_leaves(&bt);
+    let tree_id = BehaviorTree::create(ctx, bt).id;
+    ArchetypeBehaviorTree::register::<ScreamerZombieAction>(
+        ctx,
+        SCREAMER_ZOMBIE_ARCHETYPE_ID,
+        tree_id,
+        leaves,
+    );
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use spacetime_engine::{math::Vec3, utils::Entity};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::{
+    math::lerp,
+    tables::player::Player,
+    zones::{Zone, ZoneKind},
+};
+
+const SPITTER_PROJECTILE_SPEED: f32 = 12.0;
+const SPITTER_PROJECTILE_ARC_HEIGHT: f32 = 2.5;
+/// Height above the spitter's feet the projectile is launched from.
+const SPITTER_PROJECTILE_LAUNCH_HEIGHT: f32 = 1.5;
+const SPITTER_PROJECTILE_MIN_FLIGHT_MICROS: i64 = 300_000;
+
+/// Spit travelling on a ballistic arc from `launch_position` to `target_position`,
+/// creating a [`ZoneKind::SpitterAcid`] zone where it lands.
+/// The client can reproduce the trajectory from the launch and landing times.
+#[table(name = spitter_projectile, public, scheduled(tick_spitter_projectile))]
+pub struct SpitterProjectile {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub launch_position: Vec3,
+    pub target_position: Vec3,
+    pub position: Vec3,
+    pub arc_height: f32,
+    pub launched_at: Timestamp,
+    pub lands_at: Timestamp,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl Entity for SpitterProjectile {
+    fn insert(self, ctx: &ReducerContext) -> Self {
+        ctx.db.spitter_projectile().insert(self)
+    }
+
+    fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
+        ctx.db.spitter_projectile().id().find(id)
+    }
+
+    fn iter(ctx: &ReducerContext) -> impl Iterator<Item = Self> {
+        ctx.db.spitter_projectile().iter()
+    }
+
+    fn as_map(ctx: &ReducerContext) -> HashMap<u64, Self> {
+        ctx.db
+            .spitter_projectile()
+            .iter()
+            .map(|projectile| (projectile.id, projectile))
+            .collect()
+    }
+
+    fn as_vec(ctx: &ReducerContext) -> Vec<Self> {
+        ctx.db.spitter_projectile().iter().collect()
+    }
+
+    fn update(self, ctx: &ReducerContext) -> Self {
+        ctx.db.spitter_projectile().id().update(self)
+    }
+
+    fn delete(&self, ctx: &ReducerContext) {
+        ctx.db.spitter_projectile().id().delete(self.id);
+    }
+
+    fn clear(ctx: &ReducerContext) {
+        for projectile in ctx.db.spitter_projectile().iter() {
+            projectile.delete(ctx);
+        }
+    }
+
+    fn count(ctx: &ReducerContext) -> u64 {
+        ctx.db.spitter_projectile().count()
+    }
+}
+
+impl SpitterProjectile {
+    /// Launches a projectile from a spitter standing at `origin` towards `target`.
+    /// With `lead_target`, aims where the target will be when the projectile lands
+    /// if it keeps its current velocity.
+    pub fn launch(ctx: &ReducerContext, origin: Vec3, target: &Player, lead_target: bool) -> Self {
+        let launch_position = Vec3::new(
+            origin.x,
+            origin.y + SPITTER_PROJECTILE_LAUNCH_HEIGHT,
+            origin.z,
+        );
+
+        let mut target_position = target.position;
+        let mut flight_micros = flight_duration_micros(launch_position, target_position);
+        if lead_target {
+            let flight_secs = flight_micros as f32 / 1_000_000.0;
+            let velocity = target.current_velocity(ctx);
+            target_position = Vec3::new(
+                target.position.x + velocity.x * flight_secs,
+                target.position.y,
+                target.position.z + velocity.z * flight_secs,
+            );
+            flight_micros = flight_duration_micros(launch_position, target_position);
+        }
+
+        SpitterProjectile {
+            id: 0,
+            launch_position,
+            target_position,
+            position: launch_position,
+            arc_height: SPITTER_PROJECTILE_ARC_HEIGHT,
+            launched_at: ctx.timestamp,
+            lands_at: ctx.timestamp + TimeDuration::from_micros(flight_micros),
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(50_000)),
+        }
+        .insert(ctx)
+    }
+
+    /// Position along the arc at `t` in `[0, 1]`, 0 being the launch and 1 the landing.
+    fn position_at(&self, t: f32) -> Vec3 {
+        let position = lerp(self.launch_position, self.target_position, t);
+        Vec3::new(
+            position.x,
+            position.y + 4.0 * self.arc_height * t * (1.0 - t),
+            position.z,
+        )
+    }
+}
+
+fn flight_duration_micros(from: Vec3, to: Vec3) -> i64 {
+    let micros = (from.distance(&to) / SPITTER_PROJECTILE_SPEED * 1_000_000.0) as i64;
+    micros.max(SPITTER_PROJECTILE_MIN_FLIGHT_MICROS)
+}
+
+#[reducer]
+fn tick_spitter_projectile(ctx: &ReducerContext, mut tick: SpitterProjectile) {
+    if ctx.timestamp >= tick.lands_at {
+        Zone::create(ctx, ZoneKind::SpitterAcid, tick.target_position);
+        tick.delete(ctx);
+        return;
+    }
+
+    let launched_at = tick.launched_at.to_micros_since_unix_epoch();
+    let elapsed = ctx.timestamp.to_micros_since_unix_epoch() - launched_at;
+    let flight = tick.lands_at.to_micros_since_unix_epoch() - launched_at;
+    tick.position = tick.position_at(elapsed as f32 / flight as f32);
+    tick.update(ctx);
+}
@@ -1,26 +1,44 @@
-use std::collections::HashMap;
-
 use serde::{Deserialize, Serialize};
 use spacetime_engine::{
-    behavior::{tick_behavior, Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
-    collisions::{Trigger, TriggerId},
+    behavior::{Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
     math::Vec3,
-    navigation::{DestinationReachedCondition, NavigationAgent},
-    utils::{get_delta_time, Entity, WorldEntity},
+    navigation::{DestinationReachedCondition, NavigationAgent, NavigationAgentId},
+    utils::{Entity, WorldEntity},
     world::World,
 };
-use spacetimedb::{
-    rand::seq::SliceRandom, reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration,
-    Timestamp,
-};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration};
 
 use crate::{
-    constants::{SPITTER_AOE_COLLIDER_ID, WORLD_ID},
+    archetypes::{ArchetypeBehaviorTree, ArchetypeId},
+    barricades::Barricade,
+    behavior_debug::{trace_action, tree_leaves},
+    blackboard::{Blackboard, ATTACK_COOLDOWN_TIMER},
+    constants::{SPITTER_ZOMBIE_ARCHETYPE_ID, SPITTER_ZOMBIE_COLLIDER_ID},
+    enemy::{impl_enemy_entity, tick_enemies, Enemy},
+    enemy_actions::EnemyAction,
+    enemy_body::{BodyCapsule, EnemyBody},
+    math::{angle_to, point_at_angle},
+    spitter_projectile::SpitterProjectile,
+    stuck::StuckTracker,
     tables::player::Player,
+    zombies_spawner::ZombieSpawnPoint,
 };
 
+const SPLITTER_ZOMBIE_HEALTH: f32 = 80.0;
+const SPLITTER_ZOMBIE_KILL_POINTS: u32 = 80;
+const SPLITTER_ZOMBIE_HEAD_HEIGHT: f32 = 1.5;
 const SPLITTER_ZOMBIE_COOLDOWN_MICROS: i64 = 3_000_000;
 const SPLITTER_ZOMBIE_ATTACK_RANGE: f32 = 10.0;
+/// Distance to its target the spitter tries to keep, inside its attack range.
+const SPLITTER_ZOMBIE_PREFERRED_RANGE: f32 = 7.0;
+/// The spitter backs off when any player gets closer than this.
+const SPLITTER_ZOMBIE_RETREAT_RANGE: f32 = 4.0;
+const SPLITTER_ZOMBIE_POSITION_TOLERANCE: f32 = 1.0;
+/// Angle (radians) the spitter circles around its target when strafing between shots.
+const SPLITTER_ZOMBIE_STRAFE_ANGLE: f32 = 0.6;
+const SPLITTER_ZOMBIE_STRAFE_MICROS: i64 = 1_500_000;
+
+const STRAFE_TIMER: &str = "strafe";
 
 pub type SpitterZombieId = u64;
 
@@ -33,135 +51,94 @@ pub struct SpitterZombie {
     #[index(btree)]
     pub navigation_agent_id: u64,
     pub position: Vec3,
+    pub health: f32,
     pub target_player: Option<u64>,
     pub is_attacking: bool,
-    pub next_attack_time: Timestamp,
+    pub barricade_id: Option<u64>,
+    pub blackboard: Blackboard,
+    pub stuck: StuckTracker,
+    pub body: EnemyBody,
 }
 
-impl Entity for SpitterZombie {
-    fn insert(self, ctx: &ReducerContext) -> Self {
-        ctx.db.spitter_zombie().insert(self)
-    }
+impl_enemy_entity!(SpitterZombie, spitter_zombie);
 
-    fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
-        ctx.db.spitter_zombie().id().find(id)
-    }
+impl Enemy for SpitterZombie {
+    const ARCHETYPE_ID: ArchetypeId = SPITTER_ZOMBIE_ARCHETYPE_ID;
+    const BODY: BodyCapsule = BodyCapsule {
+        radius: 0.4,
+        height: 1.7,
+    };
 
-    fn iter(ctx: &ReducerContext) -> impl Iterator<Item = Self> {
-        ctx.db.spitter_zombie().iter()
+    fn kill_points(&self) -> u32 {
+        SPLITTER_ZOMBIE_KILL_POINTS
     }
 
-    fn as_map(ctx: &ReducerContext) -> HashMap<u64, Self> {
-        ctx.db
-            .spitter_zombie()
-            .iter()
-            .map(|zombie| (zombie.id, zombie))
-            .collect()
+    fn set_target(&mut self, player_id: u64) {
+        self.target_player = Some(player_id);
+        self.is_attacking = false;
     }
 
-    fn as_vec(ctx: &ReducerContext) -> Vec<Self> {
-        ctx.db.spitter_zombie().iter().collect()
+    fn clear_target(&mut self) {
+        self.target_player = None;
+        self.is_attacking = false;
     }
 
-    fn update(self, ctx: &ReducerContext) -> Self {
-        ctx.db.spitter_zombie().id().update(self)
-    }
-
-    fn delete(&self, ctx: &ReducerContext) {
-        NavigationAgent::find(ctx, self.navigation_agent_id)
-            .unwrap()
-            .delete(ctx);
-        ctx.db.spitter_zombie().id().delete(self.id);
-    }
-
-    fn clear(ctx: &ReducerContext) {
-        for zombie in ctx.db.spitter_zombie().iter() {
-            zombie.delete(ctx);
-        }
-    }
-
-    fn count(ctx: &ReducerContext) -> u64 {
-        ctx.db.spitter_zombie().count()
-    }
-}
-
-impl SpitterZombie {
-    pub fn create(ctx: &ReducerContext, position: Vec3) -> Self {
-        let navigation_agent_id = NavigationAgent::builder()
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId {
+        NavigationAgent::builder()
             .desired_speed(3.0)
             .max_speed(5.0)
             .radius(0.3)
             .target_reached_condition(DestinationReachedCondition::Distance(Some(
-                SPLITTER_ZOMBIE_ATTACK_RANGE,
+                SPLITTER_ZOMBIE_POSITION_TOLERANCE,
             )))
             .position(position)
             .build()
             .insert(ctx)
-            .id();
-        ctx.db.spitter_zombie().insert(SpitterZombie {
-            id: 0,
-            navigation_agent_id,
-            position,
-            target_player: None,
-            is_attacking: false,
-            next_attack_time: Timestamp::from_micros_since_unix_epoch(0),
-        })
-    }
-}
-
-#[table(name = spitter_aoe, public, scheduled(tick_spitter_aoe))]
-pub struct SpitterAoE {
-    #[primary_key]
-    #[auto_inc]
-    pub id: u64,
-    pub trigger_id: TriggerId,
-    pub position: Vec3,
-    pub delete_at: Timestamp,
-    pub scheduled_at: ScheduleAt,
-}
-
-impl Entity for SpitterAoE {
-    fn insert(self, ctx: &ReducerContext) -> Self {
-        ctx.db.spitter_aoe().insert(self)
-    }
-
-    fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
-        ctx.db.spitter_aoe().id().find(id)
-    }
-
-    fn iter(ctx: &ReducerContext) -> impl Iterator<Item = Self> {
-        ctx.db.spitter_aoe().iter()
-    }
-
-    fn as_map(ctx: &ReducerContext) -> HashMap<u64, Self> {
-        ctx.db
-            .spitter_aoe()
-            .iter()
-            .map(|aoe| (aoe.id, aoe))
-            .collect()
-    }
-
-    fn as_vec(ctx: &ReducerContext) -> Vec<Self> {
-        ctx.db.spitter_aoe().iter().collect()
-    }
-
-    fn update(self, ctx: &ReducerContext) -> Self {
-        ctx.db.spitter_aoe().id().update(self)
+            .id()
     }
 
-    fn delete(&self, ctx: &ReducerContext) {
-        Trigger::find(ctx, self.trigger_id).unwrap().delete(ctx);
-        ctx.db.spitter_aoe().id().delete(self.id);
+    /// The spitter keeps its distance instead of walking up to its target.
+    fn chase_destination(&self, _ctx: &ReducerContext, player: &Player) -> Vec3 {
+        self.kiting_position(player, 0.0)
     }
+}
 
-    fn clear(ctx: &ReducerContext) {
-        for aoe in ctx.db.spitter_aoe().iter() {
-            aoe.delete(ctx);
+impl SpitterZombie {
+    pub fn create(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint) -> Self {
+        let position = spawn_point.position;
+        let spitter = SpitterZombie {
+            id: 0,
+            navigation_agent_id: Self::create_navigation_agent(ctx, position),
+            position,
+            health: SPLITTER_ZOMBIE_HEALTH,
+            target_player: None,
+            is_attacking: false,
+            barricade_id: Barricade::find_for_spawn_point(ctx, spawn_point.id)
+                .map(|barricade| barricade.id),
+            blackboard: Blackboard::default(),
+            stuck: StuckTracker::new(position),
+            body: EnemyBody::create(
+                ctx,
+                SPITTER_ZOMBIE_COLLIDER_ID,
+                SPLITTER_ZOMBIE_HEAD_HEIGHT,
+                position,
+            ),
         }
+        .insert(ctx);
+        spitter
+            .body
+            .register(ctx, SPITTER_ZOMBIE_ARCHETYPE_ID, spitter.id);
+        spitter
     }
 
-    fn count(ctx: &ReducerContext) -> u64 {
-        ctx.db.spitter_aoe().count()
+    /// Point at the preferred range from `player`, on the side the spitter is on,
+    /// rotated around the player by `angle_offset`.
+    fn kiting_position(&self, player: &Player, angle_offset: f32) -> Vec3 {
+        point_at_angle(
+            player.position,
+            angle_to(player.position, self.position) + angle_offset,
+            SPLITTER_ZOMBIE_PREFERRED_RANGE,
+        )
     }
 }
 
@@ -185,20 +162,18 @@ impl SpitterZombieUpdateTick {
 }
 
 #[reducer]
-fn tick_spitter_zombie(ctx: &ReducerContext, tick: SpitterZombieUpdateTick) {
-    let delta_time = get_delta_time(tick.scheduled_at);
-    let world = World::find(ctx, WORLD_ID).expect("World not found");
-    let mut zombies = SpitterZombie::as_vec(ctx);
-    tick_behavior(ctx, &world, 1, delta_time, &mut zombies);
+fn tick_spitter_zombie(ctx: &ReducerContext, tick: SpitterZombieUpdateTick) -> Result<(), String> {
+    tick_enemies::<SpitterZombie, SpitterZombieAction>(ctx, tick.scheduled_at)
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug)]
 enum SpitterZombieAction {
-    IsMoving,
-    TargetRandomPlayer,
-    Chase,
-    IsTargetReached,
+    IsPlayerTooClose,
+    Retreat,
+    IsStrafing,
+    Strafe,
     Attack,
+    Enemy(EnemyAction),
 }
 
 impl BehaviorExecutor<SpitterZombieAction> for SpitterZombie {
@@ -209,62 +184,84 @@ impl BehaviorExecutor<SpitterZombieAction> for SpitterZombie {
         _dt: f32,
         action: &SpitterZombieAction,
     ) -> Status {
+        let status = self.execute_action(ctx, action);
+        trace_action(ctx, SPITTER_ZOMBIE_ARCHETYPE_ID, self.id, action, &status);
+        status
+    }
+}
+
+impl SpitterZombie {
+    fn execute_action(&mut self, ctx: &ReducerContext, action: &SpitterZombieAction) -> Status {
         let mut agent = NavigationAgent::find(ctx, self.navigation_agent_id)
             .expect("NavigationAgent not found");
         match action {
-            SpitterZombieAction::IsMoving => match agent.is_moving() {
-                true => Status::Success,
-                false => Status::Failure,
-            },
-            SpitterZombieAction::IsTargetReached => match agent.has_reached_destination() {
-                true => Status::Success,
-                false => Status::Failure,
-            },
-            SpitterZombieAction::TargetRandomPlayer => {
-                let players = Player::as_vec(ctx);
-                if players.is_empty() {
-                    return Status::Failure;
+            SpitterZombieAction::IsPlayerTooClose => {
+                match Player::iter(ctx).any(|player| {
+                    player.position.distance(&agent.position()) < SPLITTER_ZOMBIE_RETREAT_RANGE
+                }) {
+                    true => Status::Success,
+                    false => Status::Failure,
                 }
-
-                let target = players.choose(&mut ctx.rng()).unwrap();
+            }
+            SpitterZombieAction::Retreat => {
+                let closest_player = Player::iter(ctx).min_by(|a, b| {
+                    a.position
+                        .distance(&agent.position())
+                        .total_cmp(&b.position.distance(&agent.position()))
+                });
+                let Some(closest_player) = closest_player else {
+                    return Status::Failure;
+                };
 
                 agent
-                    .set_paused(false)
-                    .set_destination(Some(target.position));
+                    .set_destination(Some(self.kiting_position(&closest_player, 0.0)))
+                    .set_paused(false);
                 agent.update(ctx);
 
-                self.target_player = Some(target.id);
                 self.is_attacking = false;
+                self.blackboard.clear_timer(STRAFE_TIMER);
                 self.clone().update(ctx);
                 Status::Success
             }
-            SpitterZombieAction::Chase => {
-                if let Some(player) = Player::find(ctx, self.target_player.unwrap()) {
-                    agent
-                        .set_destination(Some(player.position))
-                        .set_paused(false);
-                    agent.update(ctx);
-                    return Status::Success;
+            SpitterZombieAction::IsStrafing => {
+                match self.blackboard.timer_elapsed(ctx, STRAFE_TIMER) {
+                    true => Status::Failure,
+                    false => Status::Success,
                 }
+            }
+            SpitterZombieAction::Strafe => {
+                let Some(player) = self.find_target_player(ctx) else {
+                    return Status::Failure;
+                };
 
-                // Target player not found, fail the action to re-target
-                Status::Failure
+                let angle_offset = match ctx.random::<bool>() {
+                    true => SPLITTER_ZOMBIE_STRAFE_ANGLE,
+                    false => -SPLITTER_ZOMBIE_STRAFE_ANGLE,
+                };
+                agent
+                    .set_destination(Some(self.kiting_position(&player, angle_offset)))
+                    .set_paused(false);
+                agent.update(ctx);
+
+                self.blackboard.set_timer(
+                    STRAFE_TIMER,
+                    ctx.timestamp + TimeDuration::from_micros(SPLITTER_ZOMBIE_STRAFE_MICROS),
+                );
+                self.clone().update(ctx);
+                Status::Success
             }
             SpitterZombieAction::Attack => {
-                let player = Player::find(ctx, self.target_player.unwrap());
-
-                if player.is_none() {
+                let Some(player) = self.find_target_player(ctx) else {
                     return Status::Failure;
-                }
+                };
 
-                if ctx.timestamp <= self.next_attack_time {
+                // Fail while on cooldown so the spitter strafes between shots
+                if !self.blackboard.timer_elapsed(ctx, ATTACK_COOLDOWN_TIMER) {
                     self.is_attacking = false;
                     self.clone().update(ctx);
-                    return Status::Success;
+                    return Status::Failure;
                 }
 
-                let player = player.unwrap();
-
                 if player.position.distance(&agent.position()) > SPLITTER_ZOMBIE_ATTACK_RANGE {
                     self.is_attacking = false;
                     self.clone().update(ctx);
@@ -272,58 +269,72 @@ impl BehaviorExecutor<SpitterZombieAction> for SpitterZombie {
                 }
 
                 self.is_attacking = true;
-                self.next_attack_time =
-                    ctx.timestamp + TimeDuration::from_micros(SPLITTER_ZOMBIE_COOLDOWN_MICROS);
+                self.blackboard.set_timer(
+                    ATTACK_COOLDOWN_TIMER,
+                    ctx.timestamp + TimeDuration::from_micros(SPLITTER_ZOMBIE_COOLDOWN_MICROS),
+                );
                 self.clone().update(ctx);
 
-                let trigger_id = Trigger::builder()
-                    .position(player.position)
-                    .collider_id(SPITTER_AOE_COLLIDER_ID)
-                    .build()
-                    .insert(ctx)
-                    .id;
-                SpitterAoE {
-                    id: 0,
-                    trigger_id,
-                    position: player.position,
-                    delete_at: ctx.timestamp
-                        + TimeDuration::from_micros(SPLITTER_ZOMBIE_COOLDOWN_MICROS),
-                    scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(500_000)),
-                }
-                .insert(ctx);
+                SpitterProjectile::launch(ctx, self.position, &player, true);
 
                 Status::Success
             }
+            SpitterZombieAction::Enemy(action) => action.run(ctx, self, agent),
         }
     }
 }
 
 pub fn create_spitter_zombie_behavior_tree(ctx: &ReducerContext) {
     let bt = Select(vec![
+        // Tear down the barricade of our spawn point before getting in
+        Sequence(vec![
+            Action(SpitterZombieAction::Enemy(EnemyAction::IsBehindBarricade)),
+            Action(SpitterZombieAction::Enemy(EnemyAction::AttackObstacle)),
+        ]),
+        // If we are stuck, repath, then side-step and finally teleport to the nearest spawn
+        Sequence(vec![
+            Action(SpitterZombieAction::Enemy(EnemyAction::IsStuck)),
+            Select(vec![
+                Action(SpitterZombieAction::Enemy(EnemyAction::Repath)),
+                Action(SpitterZombieAction::Enemy(EnemyAction::SideStep)),
+                Action(SpitterZombieAction::Enemy(EnemyAction::TeleportToSpawn)),
+            ]),
+        ]),
+        // Let a side-step play out before chasing again
+        Action(SpitterZombieAction::Enemy(EnemyAction::IsRecovering)),
+        // Let a knock back push us before chasing again
+        Action(SpitterZombieAction::Enemy(EnemyAction::IsKnockedBack)),
+        // If a player got too close, back off to the preferred range
+        Sequence(vec![
+            Action(SpitterZombieAction::IsPlayerTooClose),
+            Action(SpitterZombieAction::Retreat),
+        ]),
+        // Let a strafe play out before chasing again
+        Action(SpitterZombieAction::IsStrafing),
         // If we are moving, chase the target (i.e update target position)
         Sequence(vec![
-            Action(SpitterZombieAction::IsMoving),
-            Action(SpitterZombieAction::Chase),
+            Action(SpitterZombieAction::Enemy(EnemyAction::IsMoving)),
+            Action(SpitterZombieAction::Enemy(EnemyAction::Chase)),
         ]),
         // If we reached the target, attack
         Sequence(vec![
-            Action(SpitterZombieAction::IsTargetReached),
+            Action(SpitterZombieAction::Enemy(EnemyAction::IsTargetReached)),
             Action(SpitterZombieAction::Attack),
         ]),
+        // Otherwise strafe around the target until the attack is ready again
+        Sequence(vec![
+            Action(SpitterZombieAction::Enemy(EnemyAction::IsTargetReached)),
+            Action(SpitterZombieAction::Strafe),
+        ]),
         // Otherwise, target a random player
-        Action(SpitterZombieAction::TargetRandomPlayer),
+        Action(SpitterZombieAction::Enemy(EnemyAction::TargetRandomPlayer)),
     ]);
-    BehaviorTree::create(ctx, bt);
-}
-
-#[reducer]
-fn tick_spitter_aoe(ctx: &ReducerContext, tick: SpitterAoE) {
-    let trigger = Trigger::find(ctx, tick.trigger_id).unwrap();
-
-    if ctx.timestamp >= tick.delete_at {
-        tick.delete(ctx);
-        return;
-    }
-
-    log::debug!("SpitterAoE#{} trigger={:?}", tick.id, trigger);
+    let leaves = tree_leaves(&bt);
+    let tree_id = BehaviorTree::create(ctx, bt).id;
+    ArchetypeBehaviorTree::register::<SpitterZombieAction>(
+        ctx,
+        SPITTER_ZOMBIE_ARCHETYPE_ID,
+        tree_id,
+        leaves,
+    );
 }
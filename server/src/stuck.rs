@@ -0,0 +1,168 @@
+use std::f32::consts::TAU;
+
+use spacetime_engine::math::Vec3;
+use spacetimedb::{ReducerContext, SpacetimeType, TimeDuration};
+
+use crate::{blackboard::Blackboard, math::point_at_angle};
+
+pub const STUCK_RECOVERY_TIMER: &str = "stuck_recovery";
+
+/// World ticks without progress before an agent is considered stuck.
+const STUCK_TICKS: u32 = 20;
+/// Distance an agent has to cover from its anchor to count as making progress.
+const STUCK_PROGRESS_DISTANCE: f32 = 0.5;
+const SIDE_STEP_DISTANCE: f32 = 2.0;
+const SIDE_STEP_DURATION_MICROS: i64 = 1_500_000;
+
+/// Number of recovery attempts after which each recovery is no longer tried.
+pub const REPATH_ATTEMPTS: u32 = 1;
+pub const SIDE_STEP_ATTEMPTS: u32 = 2;
+
+/// Tracks the progress of a moving navigation agent, sampled on every world tick.
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct StuckTracker {
+    pub anchor: Vec3,
+    pub ticks_without_progress: u32,
+    /// Recoveries tried since the agent last made progress.
+    pub recoveries: u32,
+    /// Number of times the agent got stuck since it spawned.
+    pub stuck_count: u32,
+}
+
+impl StuckTracker {
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            anchor: position,
+            ticks_without_progress: 0,
+            recoveries: 0,
+            stuck_count: 0,
+        }
+    }
+
+    pub fn sample(&mut self, position: Vec3, is_moving: bool) {
+        if position.distance(&self.anchor) >= STUCK_PROGRESS_DISTANCE {
+            self.anchor = position;
+            self.ticks_without_progress = 0;
+            self.recoveries = 0;
+            return;
+        }
+
+        if !is_moving {
+            self.anchor = position;
+            self.ticks_without_progress = 0;
+            return;
+        }
+
+        self.ticks_without_progress += 1;
+        if self.ticks_without_progress == STUCK_TICKS {
+            self.stuck_count += 1;
+        }
+    }
+
+    pub fn is_stuck(&self) -> bool {
+        self.ticks_without_progress >= STUCK_TICKS
+    }
+
+    /// Forgets any progress and recovery, used when the agent is moved elsewhere.
+    pub fn reset(&mut self, position: Vec3) {
+        self.anchor = position;
+        self.ticks_without_progress = 0;
+        self.recoveries = 0;
+    }
+
+    /// Starts a recovery if fewer than `attempts` recoveries were tried since the
+    /// agent last made progress.
+    pub fn begin_recovery(&mut self, attempts: u32) -> bool {
+        if self.recoveries >= attempts {
+            return false;
+        }
+
+        self.recoveries += 1;
+        self.ticks_without_progress = 0;
+        true
+    }
+}
+
+/// Picks a random point next to `position` and keeps the agent heading to it for a
+/// short while, see [`STUCK_RECOVERY_TIMER`].
+pub fn side_step(ctx: &ReducerContext, blackboard: &mut Blackboard, position: Vec3) -> Vec3 {
+    blackboard.set_timer(
+        STUCK_RECOVERY_TIMER,
+        ctx.timestamp + TimeDuration::from_micros(SIDE_STEP_DURATION_MICROS),
+    );
+    point_at_angle(position, ctx.random::<f32>() * TAU, SIDE_STEP_DISTANCE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_in_place(tracker: &mut StuckTracker, ticks: u32) {
+        for _ in 0..ticks {
+            tracker.sample(Vec3::ZERO, true);
+        }
+    }
+
+    #[test]
+    fn moving_without_progress_gets_stuck() {
+        let mut tracker = StuckTracker::new(Vec3::ZERO);
+        sample_in_place(&mut tracker, STUCK_TICKS - 1);
+        assert!(!tracker.is_stuck());
+
+        sample_in_place(&mut tracker, 1);
+        assert!(tracker.is_stuck());
+        assert_eq!(tracker.stuck_count, 1);
+
+        // Staying stuck doesn't count as getting stuck again
+        sample_in_place(&mut tracker, 5);
+        assert_eq!(tracker.stuck_count, 1);
+    }
+
+    #[test]
+    fn standing_still_is_not_stuck() {
+        let mut tracker = StuckTracker::new(Vec3::ZERO);
+        for _ in 0..STUCK_TICKS * 2 {
+            tracker.sample(Vec3::ZERO, false);
+        }
+        assert!(!tracker.is_stuck());
+    }
+
+    #[test]
+    fn progress_resets_the_ticks_and_recoveries() {
+        let mut tracker = StuckTracker::new(Vec3::ZERO);
+        sample_in_place(&mut tracker, STUCK_TICKS);
+        assert!(tracker.begin_recovery(REPATH_ATTEMPTS));
+
+        let position = Vec3::new(STUCK_PROGRESS_DISTANCE, 0.0, 0.0);
+        tracker.sample(position, true);
+        assert_eq!(tracker.ticks_without_progress, 0);
+        assert_eq!(tracker.recoveries, 0);
+        assert_eq!(tracker.anchor.x, position.x);
+    }
+
+    #[test]
+    fn recoveries_are_tried_a_limited_number_of_times() {
+        let mut tracker = StuckTracker::new(Vec3::ZERO);
+        sample_in_place(&mut tracker, STUCK_TICKS);
+
+        assert!(tracker.begin_recovery(REPATH_ATTEMPTS));
+        assert!(!tracker.is_stuck());
+        assert!(!tracker.begin_recovery(REPATH_ATTEMPTS));
+
+        // Side-steps are tried once the repath was used up
+        sample_in_place(&mut tracker, STUCK_TICKS);
+        assert!(tracker.begin_recovery(SIDE_STEP_ATTEMPTS));
+        assert!(!tracker.begin_recovery(SIDE_STEP_ATTEMPTS));
+    }
+
+    #[test]
+    fn reset_moves_the_anchor() {
+        let mut tracker = StuckTracker::new(Vec3::ZERO);
+        sample_in_place(&mut tracker, STUCK_TICKS);
+        tracker.reset(Vec3::new(10.0, 0.0, 0.0));
+        assert!(!tracker.is_stuck());
+
+        tracker.sample(Vec3::new(10.0, 0.0, 0.0), true);
+        assert_eq!(tracker.ticks_without_progress, 1);
+    }
+}
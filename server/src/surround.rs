@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use spacetime_engine::math::Vec3;
+
+use crate::math::{angle_to, point_at_angle};
+
+/// Distance between a player and the slots of the inner ring, close enough to attack from.
+const SURROUND_INNER_RADIUS: f32 = 0.7;
+/// Distance between two rings, the outer rings wait for a spot on the inner one.
+const SURROUND_RING_SPACING: f32 = 1.2;
+const SURROUND_SLOTS_PER_RING: usize = 8;
+
+/// Blackboard position of the slot a chaser was given on the last behavior tick.
+pub const SURROUND_SLOT: &str = "surround_slot";
+
+/// Slots around `target` for every `(id, position)` chasing it, by chaser id.
+/// Chasers keep the side they approach from but are spread evenly, so they flank
+/// the target instead of queueing behind each other.
+pub fn surround_slots(
+    target: Vec3,
+    chasers: impl IntoIterator<Item = (u64, Vec3)>,
+) -> HashMap<u64, Vec3> {
+    let mut chasers: Vec<(u64, f32, f32)> = chasers
+        .into_iter()
+        .map(|(id, position)| (id, position.distance(&target), angle_to(target, position)))
+        .collect();
+
+    // The closest chasers get the inner ring
+    chasers.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+
+    let mut slots = HashMap::new();
+    for (ring, ring_chasers) in chasers.chunks(SURROUND_SLOTS_PER_RING).enumerate() {
+        // Within a ring, slots are handed out in the order the chasers are around the target
+        let mut ring_chasers = ring_chasers.to_vec();
+        ring_chasers.sort_by(|a, b| a.2.total_cmp(&b.2).then(a.0.cmp(&b.0)));
+        let step = std::f32::consts::TAU / ring_chasers.len() as f32;
+        let radius = SURROUND_INNER_RADIUS + SURROUND_RING_SPACING * ring as f32;
+
+        for (slot, chaser) in ring_chasers.iter().enumerate() {
+            let angle = ring_chasers[0].2 + step * slot as f32;
+            slots.insert(chaser.0, point_at_angle(target, angle, radius));
+        }
+    }
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chasers_around(count: u64, distance: f32) -> Vec<(u64, Vec3)> {
+        (0..count)
+            .map(|id| {
+                let angle = std::f32::consts::TAU * id as f32 / count as f32;
+                (id, point_at_angle(Vec3::ZERO, angle, distance))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn no_chasers_no_slots() {
+        assert!(surround_slots(Vec3::ZERO, []).is_empty());
+    }
+
+    #[test]
+    fn every_chaser_gets_an_inner_slot() {
+        let slots = surround_slots(Vec3::ZERO, chasers_around(4, 10.0));
+        assert_eq!(slots.len(), 4);
+        for slot in slots.values() {
+            assert!((slot.distance(&Vec3::ZERO) - SURROUND_INNER_RADIUS).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn chasers_from_the_same_side_are_spread_out() {
+        let chasers = [
+            (1, Vec3::new(10.0, 0.0, 0.0)),
+            (2, Vec3::new(11.0, 0.0, 0.0)),
+        ];
+        let slots = surround_slots(Vec3::ZERO, chasers);
+        // Two chasers share the ring, on opposite sides of the target
+        assert!((slots[&1].distance(&slots[&2]) - SURROUND_INNER_RADIUS * 2.0).abs() < 1e-4);
+        // The closest one keeps its side
+        assert!(slots[&1].x > 0.0);
+    }
+
+    #[test]
+    fn farthest_chasers_wait_on_the_outer_ring() {
+        let mut chasers = chasers_around(SURROUND_SLOTS_PER_RING as u64, 5.0);
+        chasers.push((100, Vec3::new(20.0, 0.0, 0.0)));
+
+        let slots = surround_slots(Vec3::ZERO, chasers);
+        let outer_radius = SURROUND_INNER_RADIUS + SURROUND_RING_SPACING;
+        assert!((slots[&100].distance(&Vec3::ZERO) - outer_radius).abs() < 1e-4);
+        for id in 0..SURROUND_SLOTS_PER_RING as u64 {
+            assert!((slots[&id].distance(&Vec3::ZERO) - SURROUND_INNER_RADIUS).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use spacetime_engine::utils::Entity;
+use spacetimedb::{table, ReducerContext, SpacetimeType, Table};
+
+use crate::{archetypes::ArchetypeId, tables::player::Player};
+
+/// Kind of game entity owning a rigid body.
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EntityKind {
+    Player,
+    Enemy(ArchetypeId),
+}
+
+/// Game entity owning an engine rigid body, so that the rigid bodies reported by
+/// triggers can be mapped back to players and enemies.
+#[table(name = entity_rigid_body, public)]
+pub struct EntityRigidBody {
+    #[primary_key]
+    pub rigid_body_id: u64,
+    pub kind: EntityKind,
+    pub entity_id: u64,
+}
+
+impl EntityRigidBody {
+    pub fn register(
+        ctx: &ReducerContext,
+        rigid_body_id: u64,
+        kind: EntityKind,
+        entity_id: u64,
+    ) -> Self {
+        ctx.db.entity_rigid_body().insert(EntityRigidBody {
+            rigid_body_id,
+            kind,
+            entity_id,
+        })
+    }
+
+    pub fn find(ctx: &ReducerContext, rigid_body_id: u64) -> Option<Self> {
+        ctx.db
+            .entity_rigid_body()
+            .rigid_body_id()
+            .find(rigid_body_id)
+    }
+
+    /// Player owning the rigid body, if it belongs to a player.
+    pub fn find_player(ctx: &ReducerContext, rigid_body_id: u64) -> Option<Player> {
+        let entity = Self::find(ctx, rigid_body_id)?;
+        match entity.kind {
+            EntityKind::Player => Player::find(ctx, entity.entity_id),
+            EntityKind::Enemy(_) => None,
+        }
+    }
+
+    pub fn unregister(ctx: &ReducerContext, rigid_body_id: u64) {
+        ctx.db
+            .entity_rigid_body()
+            .rigid_body_id()
+            .delete(rigid_body_id);
+    }
+}
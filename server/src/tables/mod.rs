@@ -1,2 +1,4 @@
+pub mod entity_rigid_body;
 pub mod player;
 pub mod zombie;
+pub mod zombie_pack;
@@ -5,9 +5,29 @@ use spacetime_engine::{
     math::Vec3,
     utils::{Entity, WorldEntity},
 };
-use spacetimedb::{table, Identity, ReducerContext, Table, Timestamp};
+use spacetimedb::{table, Identity, ReducerContext, Table, TimeDuration, Timestamp};
 
-use crate::constants::{PLAYER_COLLIDER_ID, WORLD_ID};
+use crate::{
+    boss_zombie::BossZombie,
+    brute_zombie::BruteZombie,
+    constants::{PLAYER_COLLIDER_ID, PLAYER_MAX_HEALTH, PLAYER_STARTING_POINTS, WORLD_ID},
+    enemy::clear_target_player,
+    exploder_zombie::ExploderZombie,
+    perks::PlayerPerk,
+    power_ups::{ActivePowerUp, PowerUpKind},
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
+    spitter_zombie::SpitterZombie,
+    tables::{
+        entity_rigid_body::{EntityKind, EntityRigidBody},
+        zombie::Zombie,
+        zombie_pack::ZombiePack,
+    },
+    weapons::PlayerWeapon,
+};
+
+/// Velocity is considered zero when no position was received for this long.
+const VELOCITY_STALE_MICROS: i64 = 200_000;
 
 #[table(name = player, public)]
 pub struct Player {
@@ -19,7 +39,16 @@ pub struct Player {
     pub joined_at: Timestamp,
     pub name: String,
     pub position: Vec3,
+    /// Estimated from the last two position updates sent by the client, see [`Player::current_velocity`].
+    pub velocity: Vec3,
+    /// Unset until the client sent its first position.
+    pub position_updated_at: Option<Timestamp>,
     pub rigid_body_id: u64,
+    pub health: f32,
+    pub points: u32,
+    /// Last impulse applied by the server, the client moves the player when `knocked_back_at` changes.
+    pub knockback: Vec3,
+    pub knocked_back_at: Timestamp,
 }
 
 impl Entity for Player {
@@ -55,6 +84,19 @@ impl Entity for Player {
         if let Some(rb) = RigidBody::find(ctx, self.rigid_body_id) {
             rb.delete(ctx);
         }
+        EntityRigidBody::unregister(ctx, self.rigid_body_id);
+
+        clear_target_player::<Zombie>(ctx, self.id);
+        clear_target_player::<SpitterZombie>(ctx, self.id);
+        clear_target_player::<RunnerZombie>(ctx, self.id);
+        clear_target_player::<BruteZombie>(ctx, self.id);
+        clear_target_player::<ExploderZombie>(ctx, self.id);
+        clear_target_player::<ScreamerZombie>(ctx, self.id);
+        clear_target_player::<BossZombie>(ctx, self.id);
+        ZombiePack::clear_target_player(ctx, self.id);
+
+        PlayerWeapon::clear_for(ctx, self.id);
+        PlayerPerk::clear_for(ctx, self.id);
 
         ctx.db.player().id().delete(self.id);
     }
@@ -77,15 +119,70 @@ impl Player {
             .collider_id(PLAYER_COLLIDER_ID)
             .build()
             .insert(ctx);
-        Player {
+        let player = Player {
             id: 0,
             name: format!("Player-{}", ctx.sender.to_abbreviated_hex()),
             joined_at: ctx.timestamp,
             identity: ctx.sender,
             position: Vec3::new(0.0, 0.0, 0.0),
+            velocity: Vec3::ZERO,
+            position_updated_at: None,
             rigid_body_id: rb.id,
+            health: PLAYER_MAX_HEALTH,
+            points: PLAYER_STARTING_POINTS,
+            knockback: Vec3::ZERO,
+            knocked_back_at: ctx.timestamp,
         }
-        .insert(ctx)
+        .insert(ctx);
+        EntityRigidBody::register(ctx, player.rigid_body_id, EntityKind::Player, player.id);
+        player
+    }
+
+    pub fn damage(mut self, ctx: &ReducerContext, amount: f32) -> Self {
+        self.health = (self.health - amount).max(0.0);
+        self.update(ctx)
+    }
+
+    pub fn add_points(mut self, ctx: &ReducerContext, amount: u32) -> Self {
+        let amount = match ActivePowerUp::is_active(ctx, PowerUpKind::DoublePoints) {
+            true => amount.saturating_mul(2),
+            false => amount,
+        };
+        self.points = self.points.saturating_add(amount);
+        self.update(ctx)
+    }
+
+    /// Takes `amount` points for a purchase, failing without changes if the player can't afford it.
+    pub fn spend_points(mut self, ctx: &ReducerContext, amount: u32) -> Result<Self, String> {
+        if self.points < amount {
+            return Err(format!(
+                "Not enough points: {} needed, {} available",
+                amount, self.points
+            ));
+        }
+
+        self.points -= amount;
+        Ok(self.update(ctx))
+    }
+
+    /// Velocity of the player, zero once the client stopped sending positions.
+    /// Clients only send a position when the player moved.
+    pub fn current_velocity(&self, ctx: &ReducerContext) -> Vec3 {
+        match self.position_updated_at {
+            Some(updated_at)
+                if ctx.timestamp
+                    <= updated_at + TimeDuration::from_micros(VELOCITY_STALE_MICROS) =>
+            {
+                self.velocity
+            }
+            _ => Vec3::ZERO,
+        }
+    }
+
+    pub fn knock_back(mut self, ctx: &ReducerContext, impulse: Vec3) -> Self {
+        self.knockback = impulse;
+        self.knocked_back_at = ctx.timestamp;
+        self.update(ctx)
     }
 
     pub fn find_by_identity(ctx: &ReducerContext, identity: Identity) -> Option<Self> {
@@ -3,12 +3,30 @@ use std::collections::HashMap;
 use spacetime_engine::{
     math::Vec3,
     navigation::{DestinationReachedCondition, NavigationAgent, NavigationAgentId},
-    utils::{Entity, WorldEntity},
+    utils::Entity,
+};
+use spacetimedb::{rand::seq::IteratorRandom, table, ReducerContext};
+
+use crate::{
+    archetypes::ArchetypeId,
+    barricades::Barricade,
+    blackboard::Blackboard,
+    constants::{ZOMBIE_ARCHETYPE_ID, ZOMBIE_COLLIDER_ID},
+    enemy::{impl_enemy_entity, Enemy},
+    enemy_body::{BodyCapsule, EnemyBody},
+    melee::MeleeAttack,
+    stuck::StuckTracker,
+    surround::{surround_slots, SURROUND_SLOT},
+    tables::{player::Player, zombie_pack::ZombiePack},
+    zombies_spawner::ZombieSpawnPoint,
 };
-use spacetimedb::{table, ReducerContext, Table, Timestamp};
 
 pub type ZombieId = u64;
 
+const ZOMBIE_HEALTH: f32 = 100.0;
+const ZOMBIE_KILL_POINTS: u32 = 60;
+const ZOMBIE_HEAD_HEIGHT: f32 = 1.6;
+
 #[table(name = zombie, public)]
 #[derive(Clone)]
 pub struct Zombie {
@@ -18,78 +36,149 @@ pub struct Zombie {
     #[index(btree)]
     pub navigation_agent_id: NavigationAgentId,
     pub position: Vec3,
+    pub health: f32,
     pub target_player: Option<u64>,
     pub is_attacking: bool,
-    pub next_attack_time: Timestamp,
+    pub attack: MeleeAttack,
+    pub pack_id: Option<u64>,
+    pub barricade_id: Option<u64>,
+    pub blackboard: Blackboard,
+    pub stuck: StuckTracker,
+    pub body: EnemyBody,
 }
 
-impl Entity for Zombie {
-    fn insert(self, ctx: &ReducerContext) -> Self {
-        ctx.db.zombie().insert(self)
-    }
+impl_enemy_entity!(Zombie, zombie);
+
+impl Enemy for Zombie {
+    const ARCHETYPE_ID: ArchetypeId = ZOMBIE_ARCHETYPE_ID;
+    const BODY: BodyCapsule = BodyCapsule {
+        radius: 0.4,
+        height: 1.8,
+    };
 
-    fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
-        ctx.db.zombie().id().find(id)
+    fn kill_points(&self) -> u32 {
+        ZOMBIE_KILL_POINTS
     }
 
-    fn iter(ctx: &ReducerContext) -> impl Iterator<Item = Self> {
-        ctx.db.zombie().iter()
+    /// The surround slot was given for the previous target, if any.
+    fn set_target(&mut self, player_id: u64) {
+        self.target_player = Some(player_id);
+        self.blackboard.clear_position(SURROUND_SLOT);
     }
 
-    fn as_map(ctx: &ReducerContext) -> HashMap<u64, Self> {
-        ctx.db
-            .zombie()
-            .iter()
-            .map(|zombie| (zombie.id, zombie))
-            .collect()
+    fn clear_target(&mut self) {
+        self.target_player = None;
+        self.is_attacking = false;
+        self.attack.cancel();
     }
 
-    fn as_vec(ctx: &ReducerContext) -> Vec<Self> {
-        ctx.db.zombie().iter().collect()
+    fn create_navigation_agent(ctx: &ReducerContext, position: Vec3) -> NavigationAgentId {
+        NavigationAgent::builder()
+            .desired_speed(3.0)
+            .max_speed(5.0)
+            .radius(0.3)
+            // Zombies path to a slot around the player rather than the player itself
+            .target_reached_condition(DestinationReachedCondition::Distance(Some(0.3)))
+            .position(position)
+            .build()
+            .insert(ctx)
+            .id()
     }
 
-    fn update(self, ctx: &ReducerContext) -> Self {
-        ctx.db.zombie().id().update(self)
+    /// Zombies spread around their target instead of piling up on it. Until the next
+    /// behavior tick hands them a slot, they head to the target itself.
+    fn chase_destination(&self, _ctx: &ReducerContext, player: &Player) -> Vec3 {
+        self.blackboard
+            .position(SURROUND_SLOT)
+            .unwrap_or(player.position)
     }
 
-    fn delete(&self, ctx: &ReducerContext) {
-        NavigationAgent::find(ctx, self.navigation_agent_id)
-            .unwrap()
-            .delete(ctx);
-        ctx.db.zombie().id().delete(self.id);
+    /// Pack members commit to the target of their pack.
+    fn choose_target(&self, ctx: &ReducerContext) -> Option<Player> {
+        let pack = self
+            .pack_id
+            .and_then(|pack_id| ZombiePack::find(ctx, pack_id));
+
+        let target = match pack
+            .as_ref()
+            .and_then(|pack| pack.target_player)
+            .and_then(|id| Player::find(ctx, id))
+        {
+            Some(player) => player,
+            None => Player::iter(ctx).choose(&mut ctx.rng())?,
+        };
+        if let Some(pack) = pack {
+            pack.set_target(ctx, target.id);
+        }
+        Some(target)
     }
 
-    fn clear(ctx: &ReducerContext) {
-        for zombie in ctx.db.zombie().iter() {
-            zombie.delete(ctx);
+    /// Hands out the surround slots of every targeted player at once, rather than
+    /// once per chaser.
+    fn before_tick(ctx: &ReducerContext, zombies: &mut [Self]) {
+        let mut chasers: HashMap<u64, Vec<(u64, Vec3)>> = HashMap::new();
+        for zombie in zombies.iter() {
+            if let Some(player_id) = zombie.target_player {
+                chasers
+                    .entry(player_id)
+                    .or_default()
+                    .push((zombie.id, zombie.position));
+            }
+        }
+
+        let slots: HashMap<u64, Vec3> = chasers
+            .into_iter()
+            .filter_map(|(player_id, chasers)| {
+                let player = Player::find(ctx, player_id)?;
+                Some(surround_slots(player.position, chasers))
+            })
+            .flatten()
+            .collect();
+
+        for zombie in zombies.iter_mut() {
+            match slots.get(&zombie.id) {
+                Some(slot) => zombie.blackboard.set_position(SURROUND_SLOT, *slot),
+                None => zombie.blackboard.clear_position(SURROUND_SLOT),
+            }
         }
     }
 
-    fn count(ctx: &ReducerContext) -> u64 {
-        ctx.db.zombie().count()
+    fn on_deleted(&self, ctx: &ReducerContext) {
+        if let Some(pack_id) = self.pack_id {
+            ZombiePack::remove_member(ctx, pack_id, self.id);
+        }
     }
 }
 
 impl Zombie {
-    pub fn create(ctx: &ReducerContext, position: Vec3) -> Self {
-        let navigation_agent_id = NavigationAgent::builder()
-            .desired_speed(3.0)
-            .max_speed(5.0)
-            .radius(0.3)
-            .target_reached_condition(DestinationReachedCondition::Distance(Some(1.5)))
-            .position(position)
-            .build()
-            .insert(ctx)
-            .id();
+    pub fn create(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint) -> Self {
+        Self::create_in_pack(ctx, spawn_point, spawn_point.position, None)
+    }
 
-        Zombie {
+    /// Creates a zombie at `position`, next to the spawn point it has to get in from.
+    pub fn create_in_pack(
+        ctx: &ReducerContext,
+        spawn_point: &ZombieSpawnPoint,
+        position: Vec3,
+        pack_id: Option<u64>,
+    ) -> Self {
+        let zombie = Zombie {
             id: 0,
-            navigation_agent_id,
+            navigation_agent_id: Self::create_navigation_agent(ctx, position),
             target_player: None,
             position,
+            health: ZOMBIE_HEALTH,
             is_attacking: false,
-            next_attack_time: ctx.timestamp, // We could make it an option but its fine like this
+            attack: MeleeAttack::new(ctx),
+            pack_id,
+            barricade_id: Barricade::find_for_spawn_point(ctx, spawn_point.id)
+                .map(|barricade| barricade.id),
+            blackboard: Blackboard::default(),
+            stuck: StuckTracker::new(position),
+            body: EnemyBody::create(ctx, ZOMBIE_COLLIDER_ID, ZOMBIE_HEAD_HEIGHT, position),
         }
-        .insert(ctx)
+        .insert(ctx);
+        zombie.body.register(ctx, ZOMBIE_ARCHETYPE_ID, zombie.id);
+        zombie
     }
 }
@@ -0,0 +1,97 @@
+use spacetime_engine::utils::Entity;
+use spacetimedb::{table, ReducerContext, Table};
+
+use crate::{
+    math::point_at_angle,
+    tables::zombie::{Zombie, ZombieId},
+    zombies_spawner::ZombieSpawnPoint,
+};
+
+/// Distance between the followers and their leader when spawned.
+const PACK_SPAWN_SPACING: f32 = 1.5;
+
+/// Zombies spawned together. Followers move around the leader and the whole pack
+/// commits to the same target.
+#[table(name = zombie_pack, public)]
+pub struct ZombiePack {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub leader_id: ZombieId,
+    pub target_player: Option<u64>,
+}
+
+impl ZombiePack {
+    pub fn spawn(ctx: &ReducerContext, spawn_point: &ZombieSpawnPoint, size: u32) -> Self {
+        let pack = ctx.db.zombie_pack().insert(ZombiePack {
+            id: 0,
+            leader_id: 0,
+            target_player: None,
+        });
+
+        let position = spawn_point.position;
+        let leader = Zombie::create_in_pack(ctx, spawn_point, position, Some(pack.id));
+        let step = std::f32::consts::TAU / size.saturating_sub(1).max(1) as f32;
+        for i in 1..size {
+            let position = point_at_angle(position, step * i as f32, PACK_SPAWN_SPACING);
+            Zombie::create_in_pack(ctx, spawn_point, position, Some(pack.id));
+        }
+
+        ctx.db.zombie_pack().id().update(ZombiePack {
+            leader_id: leader.id,
+            ..pack
+        })
+    }
+
+    pub fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
+        ctx.db.zombie_pack().id().find(id)
+    }
+
+    pub fn members(&self, ctx: &ReducerContext) -> impl Iterator<Item = Zombie> {
+        let id = self.id;
+        Zombie::iter(ctx).filter(move |zombie| zombie.pack_id == Some(id))
+    }
+
+    pub fn set_target(mut self, ctx: &ReducerContext, player_id: u64) -> Self {
+        self.target_player = Some(player_id);
+        ctx.db.zombie_pack().id().update(self)
+    }
+
+    /// Promotes the oldest remaining member if the leader left, deletes the pack once empty.
+    pub fn remove_member(ctx: &ReducerContext, id: u64, zombie_id: ZombieId) {
+        let Some(mut pack) = Self::find(ctx, id) else {
+            return;
+        };
+        if pack.leader_id != zombie_id {
+            return;
+        }
+
+        match pack.members(ctx).map(|zombie| zombie.id).min() {
+            Some(leader_id) => {
+                pack.leader_id = leader_id;
+                ctx.db.zombie_pack().id().update(pack);
+            }
+            None => {
+                ctx.db.zombie_pack().id().delete(pack.id);
+            }
+        }
+    }
+
+    pub fn clear_target_player(ctx: &ReducerContext, player_id: u64) {
+        for mut pack in ctx
+            .db
+            .zombie_pack()
+            .iter()
+            .filter(|pack| pack.target_player == Some(player_id))
+        {
+            pack.target_player = None;
+            ctx.db.zombie_pack().id().update(pack);
+        }
+    }
+
+    pub fn clear(ctx: &ReducerContext) {
+        for pack in ctx.db.zombie_pack().iter() {
+            ctx.db.zombie_pack().id().delete(pack.id);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use spacetime_engine::utils::Entity;
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+
+use crate::{
+    boss_zombie::BossZombie, constants::BOSS_ROUND_INTERVAL, tables::player::player,
+    zombies_spawner::ZombieSpawnPoint,
+};
+
+const ROUND_DURATION_MICROS: i64 = 90_000_000;
+
+/// Current round of the game, there is at most one row.
+#[table(name = round, public)]
+pub struct Round {
+    #[primary_key]
+    pub id: u8,
+    pub number: u32,
+    pub started_at: Timestamp,
+    pub ends_at: Timestamp,
+}
+
+impl Round {
+    pub fn current(ctx: &ReducerContext) -> Option<Self> {
+        ctx.db.round().id().find(0)
+    }
+
+    pub fn clear(ctx: &ReducerContext) {
+        ctx.db.round().id().delete(0);
+    }
+
+    fn start(ctx: &ReducerContext, number: u32) -> Self {
+        let round = Round {
+            id: 0,
+            number,
+            started_at: ctx.timestamp,
+            ends_at: ctx.timestamp + TimeDuration::from_micros(ROUND_DURATION_MICROS),
+        };
+        match Self::current(ctx) {
+            Some(_) => ctx.db.round().id().update(round),
+            None => ctx.db.round().insert(round),
+        }
+    }
+}
+
+#[table(name = wave_director_tick, scheduled(tick_wave_director))]
+pub struct WaveDirectorTick {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl WaveDirectorTick {
+    pub fn schedule(ctx: &ReducerContext) -> Self {
+        ctx.db.wave_director_tick().insert(WaveDirectorTick {
+            id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(1_000_000)),
+        })
+    }
+}
+
+/// Moves on to the next round when the current one is over,
+/// spawning a boss every `BOSS_ROUND_INTERVAL` rounds.
+#[reducer]
+fn tick_wave_director(ctx: &ReducerContext, _tick: WaveDirectorTick) {
+    if ctx.db.player().count() == 0 {
+        return;
+    }
+
+    let number = match Round::current(ctx) {
+        Some(round) if ctx.timestamp < round.ends_at => return,
+        Some(round) => round.number + 1,
+        None => 1,
+    };
+    Round::start(ctx, number);
+    log::info!("Round {} started", number);
+
+    if number % BOSS_ROUND_INTERVAL != 0 || BossZombie::count(ctx) > 0 {
+        return;
+    }
+
+    match ZombieSpawnPoint::boss_spawn_point(ctx) {
+        Some(spawn_point) => {
+            BossZombie::create(ctx, &spawn_point);
+        }
+        None => log::warn!(
+            "Round {} should spawn a boss but there is no boss spawn",
+            number
+        ),
+    }
+}
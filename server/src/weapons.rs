@@ -0,0 +1,389 @@
+use spacetime_engine::{math::Vec3, utils::Entity};
+use spacetimedb::{reducer, table, ReducerContext, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::{
+    archetypes::ArchetypeId,
+    boss_zombie::BossZombie,
+    brute_zombie::BruteZombie,
+    constants::{
+        BOSS_ZOMBIE_ARCHETYPE_ID, BRUTE_ZOMBIE_ARCHETYPE_ID, EXPLODER_ZOMBIE_ARCHETYPE_ID,
+        RUNNER_ZOMBIE_ARCHETYPE_ID, SCREAMER_ZOMBIE_ARCHETYPE_ID, SPITTER_ZOMBIE_ARCHETYPE_ID,
+        ZOMBIE_ARCHETYPE_ID,
+    },
+    enemy::Enemy,
+    enemy_body::ENEMY_HEAD_RADIUS,
+    exploder_zombie::ExploderZombie,
+    math::{normalize, ray_capsule_distance, ray_sphere_distance},
+    noise::{NoiseEvent, NoiseKind},
+    perks::{PerkKind, PlayerPerk},
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
+    spitter_zombie::SpitterZombie,
+    tables::{player::Player, zombie::Zombie},
+};
+
+/// Height above the player's feet shots are fired from.
+const PLAYER_EYE_HEIGHT: f32 = 1.6;
+const HEADSHOT_DAMAGE_MULTIPLIER: f32 = 2.0;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeaponKind {
+    Pistol,
+    Shotgun,
+    AssaultRifle,
+}
+
+/// Stats of a weapon kind, seeded in `init`.
+#[table(name = weapon_definition, public)]
+pub struct WeaponDefinition {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub kind: WeaponKind,
+    /// Damage of each pellet.
+    pub damage: f32,
+    pub pellets: u32,
+    pub fire_interval_micros: i64,
+    pub magazine_size: u32,
+    pub max_reserve_ammo: u32,
+    pub reload_micros: i64,
+    pub range: f32,
+    /// Maximum deviation (radians) of each pellet from the aimed direction.
+    pub spread: f32,
+}
+
+impl WeaponDefinition {
+    pub fn seed(ctx: &ReducerContext) {
+        for definition in [
+            WeaponDefinition {
+                id: 0,
+                kind: WeaponKind::Pistol,
+                damage: 25.0,
+                pellets: 1,
+                fire_interval_micros: 250_000,
+                magazine_size: 8,
+                max_reserve_ammo: 80,
+                reload_micros: 1_200_000,
+                range: 40.0,
+                spread: 0.01,
+            },
+            WeaponDefinition {
+                id: 0,
+                kind: WeaponKind::Shotgun,
+                damage: 18.0,
+                pellets: 8,
+                fire_interval_micros: 900_000,
+                magazine_size: 6,
+                max_reserve_ammo: 40,
+                reload_micros: 2_500_000,
+                range: 15.0,
+                spread: 0.12,
+            },
+            WeaponDefinition {
+                id: 0,
+                kind: WeaponKind::AssaultRifle,
+                damage: 30.0,
+                pellets: 1,
+                fire_interval_micros: 100_000,
+                magazine_size: 30,
+                max_reserve_ammo: 240,
+                reload_micros: 2_000_000,
+                range: 60.0,
+                spread: 0.03,
+            },
+        ] {
+            ctx.db.weapon_definition().insert(definition);
+        }
+    }
+
+    pub fn find(ctx: &ReducerContext, kind: WeaponKind) -> Self {
+        ctx.db
+            .weapon_definition()
+            .iter()
+            .find(|definition| definition.kind == kind)
+            .expect("Weapon definition not found")
+    }
+}
+
+/// Weapon owned by a player.
+#[table(name = player_weapon, public)]
+pub struct PlayerWeapon {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: u64,
+    pub kind: WeaponKind,
+    pub magazine_ammo: u32,
+    pub reserve_ammo: u32,
+    pub is_equipped: bool,
+    pub last_fired_at: Timestamp,
+    /// The weapon can't fire before this time while reloading.
+    pub reloading_until: Timestamp,
+}
+
+impl PlayerWeapon {
+    /// Gives a fully loaded weapon to the player and equips it.
+    pub fn give(ctx: &ReducerContext, player_id: u64, kind: WeaponKind) -> Self {
+        let definition = WeaponDefinition::find(ctx, kind);
+        Self::unequip_all(ctx, player_id);
+        ctx.db.player_weapon().insert(PlayerWeapon {
+            id: 0,
+            player_id,
+            kind,
+            magazine_ammo: definition.magazine_size,
+            reserve_ammo: definition.max_reserve_ammo,
+            is_equipped: true,
+            last_fired_at: ctx.timestamp,
+            reloading_until: ctx.timestamp,
+        })
+    }
+
+    pub fn find_owned(ctx: &ReducerContext, player_id: u64, kind: WeaponKind) -> Option<Self> {
+        ctx.db
+            .player_weapon()
+            .player_id()
+            .filter(player_id)
+            .find(|weapon| weapon.kind == kind)
+    }
+
+    pub fn find_equipped(ctx: &ReducerContext, player_id: u64) -> Option<Self> {
+        ctx.db
+            .player_weapon()
+            .player_id()
+            .filter(player_id)
+            .find(|weapon| weapon.is_equipped)
+    }
+
+    pub fn refill(mut self, ctx: &ReducerContext) -> Self {
+        let definition = WeaponDefinition::find(ctx, self.kind);
+        self.reserve_ammo = definition.max_reserve_ammo;
+        ctx.db.player_weapon().id().update(self)
+    }
+
+    pub fn refill_all(ctx: &ReducerContext, player_id: u64) {
+        for weapon in ctx.db.player_weapon().player_id().filter(player_id) {
+            weapon.refill(ctx);
+        }
+    }
+
+    pub fn clear_for(ctx: &ReducerContext, player_id: u64) {
+        ctx.db.player_weapon().player_id().delete(player_id);
+    }
+
+    fn unequip_all(ctx: &ReducerContext, player_id: u64) {
+        for mut weapon in ctx.db.player_weapon().player_id().filter(player_id) {
+            if weapon.is_equipped {
+                weapon.is_equipped = false;
+                ctx.db.player_weapon().id().update(weapon);
+            }
+        }
+    }
+}
+
+/// Enemy hit by a shot.
+struct Hit {
+    archetype: ArchetypeId,
+    entity_id: u64,
+    distance: f32,
+    headshot: bool,
+}
+
+/// Closest enemy of type `E` on the ray. Hits going through the head of the enemy are headshots.
+fn closest_hit<E: Enemy>(
+    ctx: &ReducerContext,
+    origin: Vec3,
+    direction: Vec3,
+    range: f32,
+) -> Option<Hit> {
+    E::iter(ctx)
+        .filter_map(|enemy| {
+            let head_center = enemy.body().head_center(enemy.position());
+            let (distance, headshot) =
+                match ray_sphere_distance(origin, direction, head_center, ENEMY_HEAD_RADIUS) {
+                    Some(distance) => (distance, true),
+                    None => (
+                        ray_capsule_distance(
+                            origin,
+                            direction,
+                            enemy.position(),
+                            E::BODY.radius,
+                            E::BODY.height,
+                        )?,
+                        false,
+                    ),
+                };
+            (distance <= range).then_some(Hit {
+                archetype: E::ARCHETYPE_ID,
+                entity_id: enemy.id(),
+                distance,
+                headshot,
+            })
+        })
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+fn damage_enemy<E: Enemy>(ctx: &ReducerContext, id: u64, damage: f32, attacker: u64) {
+    if let Some(enemy) = E::find(ctx, id) {
+        enemy.damage(ctx, damage, Some(attacker));
+    }
+}
+
+/// Damages the first enemy on the ray, if any.
+fn shoot(
+    ctx: &ReducerContext,
+    origin: Vec3,
+    direction: Vec3,
+    range: f32,
+    damage: f32,
+    attacker: u64,
+) {
+    let hits = [
+        closest_hit::<Zombie>(ctx, origin, direction, range),
+        closest_hit::<SpitterZombie>(ctx, origin, direction, range),
+        closest_hit::<RunnerZombie>(ctx, origin, direction, range),
+        closest_hit::<BruteZombie>(ctx, origin, direction, range),
+        closest_hit::<ExploderZombie>(ctx, origin, direction, range),
+        closest_hit::<ScreamerZombie>(ctx, origin, direction, range),
+        closest_hit::<BossZombie>(ctx, origin, direction, range),
+    ];
+    let Some(hit) = hits
+        .into_iter()
+        .flatten()
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    else {
+        return;
+    };
+
+    let damage = match hit.headshot {
+        true => damage * HEADSHOT_DAMAGE_MULTIPLIER,
+        false => damage,
+    };
+    match hit.archetype {
+        ZOMBIE_ARCHETYPE_ID => damage_enemy::<Zombie>(ctx, hit.entity_id, damage, attacker),
+        SPITTER_ZOMBIE_ARCHETYPE_ID => {
+            damage_enemy::<SpitterZombie>(ctx, hit.entity_id, damage, attacker)
+        }
+        RUNNER_ZOMBIE_ARCHETYPE_ID => {
+            damage_enemy::<RunnerZombie>(ctx, hit.entity_id, damage, attacker)
+        }
+        BRUTE_ZOMBIE_ARCHETYPE_ID => {
+            damage_enemy::<BruteZombie>(ctx, hit.entity_id, damage, attacker)
+        }
+        EXPLODER_ZOMBIE_ARCHETYPE_ID => {
+            damage_enemy::<ExploderZombie>(ctx, hit.entity_id, damage, attacker)
+        }
+        SCREAMER_ZOMBIE_ARCHETYPE_ID => {
+            damage_enemy::<ScreamerZombie>(ctx, hit.entity_id, damage, attacker)
+        }
+        BOSS_ZOMBIE_ARCHETYPE_ID => {
+            damage_enemy::<BossZombie>(ctx, hit.entity_id, damage, attacker)
+        }
+        _ => {}
+    }
+}
+
+/// Rotates `direction` by a random yaw and pitch of at most `spread` radians.
+fn apply_spread(ctx: &ReducerContext, direction: Vec3, spread: f32) -> Vec3 {
+    let yaw = direction.z.atan2(direction.x) + (ctx.random::<f32>() * 2.0 - 1.0) * spread;
+    let pitch = direction.y.asin() + (ctx.random::<f32>() * 2.0 - 1.0) * spread;
+    Vec3::new(
+        pitch.cos() * yaw.cos(),
+        pitch.sin(),
+        pitch.cos() * yaw.sin(),
+    )
+}
+
+/// Fires the equipped weapon in `direction`. Hits are resolved by the server.
+#[reducer]
+fn fire(ctx: &ReducerContext, direction: Vec3) -> Result<(), String> {
+    let player = Player::find_by_identity(ctx, ctx.sender).ok_or("Player not found")?;
+    let mut weapon = PlayerWeapon::find_equipped(ctx, player.id).ok_or("No weapon equipped")?;
+    let definition = WeaponDefinition::find(ctx, weapon.kind);
+
+    if ctx.timestamp < weapon.reloading_until {
+        return Err("Reloading".to_string());
+    }
+    if ctx.timestamp
+        < weapon.last_fired_at + TimeDuration::from_micros(definition.fire_interval_micros)
+    {
+        return Err("Firing too fast".to_string());
+    }
+    if weapon.magazine_ammo == 0 {
+        return Err("Magazine empty".to_string());
+    }
+
+    let direction = normalize(direction);
+    if direction.distance(&Vec3::ZERO) == 0.0 {
+        return Err("Invalid direction".to_string());
+    }
+
+    weapon.magazine_ammo -= 1;
+    weapon.last_fired_at = ctx.timestamp;
+    ctx.db.player_weapon().id().update(weapon);
+
+    let origin = Vec3::new(
+        player.position.x,
+        player.position.y + PLAYER_EYE_HEIGHT,
+        player.position.z,
+    );
+    for _ in 0..definition.pellets {
+        let direction = apply_spread(ctx, direction, definition.spread);
+        shoot(
+            ctx,
+            origin,
+            direction,
+            definition.range,
+            definition.damage,
+            player.id,
+        );
+    }
+
+    NoiseEvent::emit(ctx, NoiseKind::Gunshot, player.position, Some(player.id));
+    Ok(())
+}
+
+/// Moves ammo from the reserve to the magazine, the weapon can fire again once the reload is over.
+#[reducer]
+fn reload(ctx: &ReducerContext) -> Result<(), String> {
+    let player = Player::find_by_identity(ctx, ctx.sender).ok_or("Player not found")?;
+    let mut weapon = PlayerWeapon::find_equipped(ctx, player.id).ok_or("No weapon equipped")?;
+    let definition = WeaponDefinition::find(ctx, weapon.kind);
+
+    if ctx.timestamp < weapon.reloading_until {
+        return Err("Already reloading".to_string());
+    }
+
+    let missing = definition.magazine_size - weapon.magazine_ammo;
+    let loaded = missing.min(weapon.reserve_ammo);
+    if loaded == 0 {
+        return Err("Nothing to reload".to_string());
+    }
+
+    let reload_micros = match PlayerPerk::has(ctx, player.id, PerkKind::SleightOfHand) {
+        true => definition.reload_micros / 2,
+        false => definition.reload_micros,
+    };
+    weapon.magazine_ammo += loaded;
+    weapon.reserve_ammo -= loaded;
+    weapon.reloading_until = ctx.timestamp + TimeDuration::from_micros(reload_micros);
+    ctx.db.player_weapon().id().update(weapon);
+    Ok(())
+}
+
+#[reducer]
+fn switch_weapon(ctx: &ReducerContext, weapon_id: u64) -> Result<(), String> {
+    let player = Player::find_by_identity(ctx, ctx.sender).ok_or("Player not found")?;
+    let mut weapon = ctx
+        .db
+        .player_weapon()
+        .id()
+        .find(weapon_id)
+        .filter(|weapon| weapon.player_id == player.id)
+        .ok_or("Weapon not owned")?;
+
+    PlayerWeapon::unequip_all(ctx, player.id);
+    weapon.is_equipped = true;
+    ctx.db.player_weapon().id().update(weapon);
+    Ok(())
+}
@@ -1,8 +1,17 @@
-use spacetime_engine::{math::Vec3, navigation::Character, utils::Entity};
+use spacetime_engine::{
+    navigation::{Character, NavigationAgentId},
+    utils::Entity,
+};
 use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table};
 
 use crate::{
+    boss_zombie::BossZombie,
+    brute_zombie::BruteZombie,
     constants::WORLD_ID,
+    enemy::sync_enemies,
+    exploder_zombie::ExploderZombie,
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
     spitter_zombie::SpitterZombie,
     tables::{player::Player, zombie::Zombie},
 };
@@ -28,23 +37,22 @@ impl WorldTick {
 pub fn tick_world(ctx: &ReducerContext, tick: WorldTick) {
     let characters = Player::iter(ctx).map(|p| Character {
         position: p.position,
-        velocity: Vec3::ZERO,
+        velocity: p.current_velocity(ctx),
         radius: 0.5,
     });
 
     let agents = spacetime_engine::world::tick_world(ctx, WORLD_ID, tick.scheduled_at, characters);
+    let agent_state = |id: NavigationAgentId| {
+        agents
+            .get(&id)
+            .map(|agent| (agent.position(), agent.is_moving()))
+    };
 
-    for mut zombie in Zombie::iter(ctx) {
-        if let Some(agent) = agents.get(&zombie.navigation_agent_id) {
-            zombie.position = agent.position();
-            zombie.update(ctx);
-        }
-    }
-
-    for mut spitter_zombie in SpitterZombie::iter(ctx) {
-        if let Some(agent) = agents.get(&spitter_zombie.navigation_agent_id) {
-            spitter_zombie.position = agent.position();
-            spitter_zombie.update(ctx);
-        }
-    }
+    sync_enemies::<Zombie>(ctx, agent_state);
+    sync_enemies::<SpitterZombie>(ctx, agent_state);
+    sync_enemies::<RunnerZombie>(ctx, agent_state);
+    sync_enemies::<BruteZombie>(ctx, agent_state);
+    sync_enemies::<ExploderZombie>(ctx, agent_state);
+    sync_enemies::<ScreamerZombie>(ctx, agent_state);
+    sync_enemies::<BossZombie>(ctx, agent_state);
 }
@@ -1,13 +1,21 @@
-use spacetime_engine::math::Vec3;
-use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration};
+use spacetime_engine::{math::Vec3, utils::Entity};
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration};
 
 use crate::{
-    constants::SPITTER_SPAWN_CHANCE,
-    spitter_zombie::SpitterZombie,
-    tables::{
-        player::player,
-        zombie::{zombie, Zombie},
+    admins::Admin,
+    brute_zombie::BruteZombie,
+    constants::{
+        BRUTE_MAX_ALIVE, BRUTE_SPAWN_CHANCE, EXPLODER_SPAWN_CHANCE, MAX_ENEMIES_ALIVE,
+        PACK_MAX_SIZE, PACK_MIN_SIZE, PACK_SPAWN_CHANCE, RUNNER_SPAWN_CHANCE,
+        SCREAMER_SPAWN_CHANCE, SPITTER_SPAWN_CHANCE, STARTING_AREA_ID,
     },
+    doors::door,
+    enemy::count_enemies,
+    exploder_zombie::ExploderZombie,
+    runner_zombie::RunnerZombie,
+    screamer_zombie::ScreamerZombie,
+    spitter_zombie::SpitterZombie,
+    tables::{player::player, zombie::Zombie, zombie_pack::ZombiePack},
 };
 
 #[table(name = zombie_spawn_tick, scheduled(spawn_zombies))]
@@ -27,41 +35,226 @@ impl ZombieSpawnTick {
     }
 }
 
+/// Ids are chosen by the level editor, barricades refer to their spawn point by id.
 #[table(name = zombie_spawn_point)]
 pub struct ZombieSpawnPoint {
     #[primary_key]
-    #[auto_inc]
     pub id: u64,
     pub position: Vec3,
+    /// Reserved for the bosses spawned by the wave director.
+    pub is_boss_spawn: bool,
+    /// Area of the map the spawn point is in, see [`Door`](crate::doors::Door).
+    pub area_id: u64,
+    /// Zombies only spawn in areas players have opened.
+    pub is_enabled: bool,
+}
+
+/// Spawn point as placed in the level editor.
+#[derive(SpacetimeType)]
+pub struct ImportedSpawnPoint {
+    pub id: u64,
+    pub position: Vec3,
+    pub area_id: u64,
+    pub is_boss_spawn: bool,
 }
 
 impl ZombieSpawnPoint {
-    pub fn create(ctx: &ReducerContext, position: Vec3) -> Self {
+    pub fn create(ctx: &ReducerContext, id: u64, position: Vec3, area_id: u64) -> Self {
+        ctx.db.zombie_spawn_point().insert(ZombieSpawnPoint {
+            id,
+            position,
+            is_boss_spawn: false,
+            area_id,
+            is_enabled: area_id == STARTING_AREA_ID,
+        })
+    }
+
+    pub fn create_boss_spawn(ctx: &ReducerContext, id: u64, position: Vec3) -> Self {
+        ctx.db.zombie_spawn_point().insert(ZombieSpawnPoint {
+            id,
+            position,
+            is_boss_spawn: true,
+            area_id: STARTING_AREA_ID,
+            is_enabled: true,
+        })
+    }
+
+    /// Enables or disables every spawn point of the area.
+    pub fn set_area_enabled(ctx: &ReducerContext, area_id: u64, is_enabled: bool) {
+        for mut spawn_point in ctx.db.zombie_spawn_point().iter() {
+            if spawn_point.area_id == area_id && !spawn_point.is_boss_spawn {
+                spawn_point.is_enabled = is_enabled;
+                ctx.db.zombie_spawn_point().id().update(spawn_point);
+            }
+        }
+    }
+
+    /// Disables the spawn points of every area but the starting one.
+    pub fn reset_areas(ctx: &ReducerContext) {
+        for mut spawn_point in ctx.db.zombie_spawn_point().iter() {
+            if spawn_point.area_id != STARTING_AREA_ID && spawn_point.is_enabled {
+                spawn_point.is_enabled = false;
+                ctx.db.zombie_spawn_point().id().update(spawn_point);
+            }
+        }
+    }
+
+    pub fn nearest(ctx: &ReducerContext, position: Vec3) -> Option<Self> {
+        ctx.db
+            .zombie_spawn_point()
+            .iter()
+            .filter(|spawn_point| !spawn_point.is_boss_spawn && spawn_point.is_enabled)
+            .min_by(|a, b| {
+                a.position
+                    .distance(&position)
+                    .total_cmp(&b.position.distance(&position))
+            })
+    }
+
+    pub fn boss_spawn_point(ctx: &ReducerContext) -> Option<Self> {
         ctx.db
             .zombie_spawn_point()
-            .insert(ZombieSpawnPoint { id: 0, position })
+            .iter()
+            .find(|spawn_point| spawn_point.is_boss_spawn)
+    }
+}
+
+/// Replaces every spawn point, boss spawns included, with the ones exported from the level.
+#[reducer]
+fn editor_import_spawn_points(
+    ctx: &ReducerContext,
+    spawn_points: Vec<ImportedSpawnPoint>,
+) -> Result<(), String> {
+    Admin::require(ctx)?;
+
+    for spawn_point in ctx.db.zombie_spawn_point().iter() {
+        ctx.db.zombie_spawn_point().id().delete(spawn_point.id);
+    }
+    // Pending requests point at the spawn points removed above
+    ZombieSpawnRequest::clear(ctx);
+
+    log::info!("Importing {} spawn points", spawn_points.len());
+    for spawn_point in spawn_points {
+        // Areas behind doors already bought stay open
+        let is_area_open = spawn_point.area_id == STARTING_AREA_ID
+            || ctx
+                .db
+                .door()
+                .iter()
+                .any(|door| door.is_open && door.area_id == spawn_point.area_id);
+
+        ctx.db
+            .zombie_spawn_point()
+            .try_insert(ZombieSpawnPoint {
+                id: spawn_point.id,
+                position: spawn_point.position,
+                is_boss_spawn: spawn_point.is_boss_spawn,
+                area_id: spawn_point.area_id,
+                is_enabled: spawn_point.is_boss_spawn || is_area_open,
+            })
+            .map_err(|_| format!("Duplicate spawn point id {}", spawn_point.id))?;
+    }
+    Ok(())
+}
+
+/// Extra zombies asked for by other entities, spawned on the next spawner tick.
+#[table(name = zombie_spawn_request)]
+pub struct ZombieSpawnRequest {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub spawn_point_id: u64,
+    pub count: u32,
+}
+
+impl ZombieSpawnRequest {
+    pub fn create(ctx: &ReducerContext, spawn_point_id: u64, count: u32) -> Self {
+        ctx.db.zombie_spawn_request().insert(ZombieSpawnRequest {
+            id: 0,
+            spawn_point_id,
+            count,
+        })
+    }
+
+    pub fn clear(ctx: &ReducerContext) {
+        for request in ctx.db.zombie_spawn_request().iter() {
+            ctx.db.zombie_spawn_request().id().delete(request.id);
+        }
     }
 }
 
 #[reducer]
 pub fn spawn_zombies(ctx: &ReducerContext, _t: ZombieSpawnTick) {
-    let zombie_count = ctx.db.zombie().count();
-    let player_count = ctx.db.player().count();
-    if zombie_count >= 100 || player_count == 0 {
+    if ctx.db.player().count() == 0 {
         return;
     }
 
     // FIXME: Remove the collect here
-    let spawn_points: Vec<ZombieSpawnPoint> = ctx.db.zombie_spawn_point().iter().collect();
+    let spawn_points: Vec<ZombieSpawnPoint> = ctx
+        .db
+        .zombie_spawn_point()
+        .iter()
+        .filter(|spawn_point| !spawn_point.is_boss_spawn && spawn_point.is_enabled)
+        .collect();
     if spawn_points.is_empty() {
         return;
     }
 
+    // Every archetype counts towards the cap, including the reinforcements requested below
+    let mut capacity = MAX_ENEMIES_ALIVE.saturating_sub(count_enemies(ctx));
+
+    let requests: Vec<ZombieSpawnRequest> = ctx.db.zombie_spawn_request().iter().collect();
+    for request in requests {
+        ctx.db.zombie_spawn_request().id().delete(request.id);
+        let Some(spawn_point) = ctx
+            .db
+            .zombie_spawn_point()
+            .id()
+            .find(request.spawn_point_id)
+        else {
+            continue;
+        };
+
+        // Reinforcements past the cap are dropped rather than delayed
+        let count = (request.count as u64).min(capacity) as u32;
+        if count == 0 {
+            continue;
+        }
+
+        // Zombies requested together move as a pack
+        ZombiePack::spawn(ctx, &spawn_point, count);
+        capacity -= count as u64;
+    }
+
+    if capacity == 0 {
+        return;
+    }
+
     let spawn_point = &spawn_points[ctx.random::<usize>() % spawn_points.len()];
 
-    if ctx.random::<f32>() <= SPITTER_SPAWN_CHANCE {
-        SpitterZombie::create(ctx, spawn_point.position);
+    if BruteZombie::count(ctx) < BRUTE_MAX_ALIVE && ctx.random::<f32>() <= BRUTE_SPAWN_CHANCE {
+        BruteZombie::create(ctx, spawn_point);
+        return;
+    }
+
+    let roll = ctx.random::<f32>();
+    if roll <= SPITTER_SPAWN_CHANCE {
+        SpitterZombie::create(ctx, spawn_point);
+    } else if roll <= SPITTER_SPAWN_CHANCE + RUNNER_SPAWN_CHANCE {
+        RunnerZombie::create(ctx, spawn_point);
+    } else if roll <= SPITTER_SPAWN_CHANCE + RUNNER_SPAWN_CHANCE + EXPLODER_SPAWN_CHANCE {
+        ExploderZombie::create(ctx, spawn_point);
+    } else if roll
+        <= SPITTER_SPAWN_CHANCE
+            + RUNNER_SPAWN_CHANCE
+            + EXPLODER_SPAWN_CHANCE
+            + SCREAMER_SPAWN_CHANCE
+    {
+        ScreamerZombie::create(ctx, spawn_point);
+    } else if ctx.random::<f32>() <= PACK_SPAWN_CHANCE {
+        let size = PACK_MIN_SIZE + ctx.random::<u32>() % (PACK_MAX_SIZE - PACK_MIN_SIZE + 1);
+        ZombiePack::spawn(ctx, spawn_point, size.min(capacity as u32));
     } else {
-        Zombie::create(ctx, spawn_point.position);
+        Zombie::create(ctx, spawn_point);
     }
 }
@@ -1,24 +1,68 @@
-use crate::constants::WORLD_ID;
+use crate::{
+    archetypes::ArchetypeBehaviorTree,
+    behavior_debug::{trace_action, tree_leaves},
+    constants::ZOMBIE_ARCHETYPE_ID,
+    enemy::{tick_enemies, Enemy},
+    enemy_actions::EnemyAction,
+    math::point_at_angle,
+    melee::{MeleeAction, MeleeAttack, MeleeAttackProfile, MeleeEnemy},
+    noise::NoiseEvent,
+};
 use serde::{Deserialize, Serialize};
 use spacetime_engine::{
-    behavior::{tick_behavior, Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
+    behavior::{Action, BehaviorExecutor, BehaviorTree, Select, Sequence, Status},
     navigation::NavigationAgent,
     utils::{Entity, WorldEntity},
     world::World,
 };
-use spacetimedb::{
-    rand::seq::SliceRandom, reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration,
+use spacetimedb::{reducer, table, ReducerContext, ScheduleAt, Table, TimeDuration};
+
+use crate::tables::{player::Player, zombie::Zombie, zombie_pack::ZombiePack};
+
+const ZOMBIE_MELEE_ATTACK: MeleeAttackProfile = MeleeAttackProfile {
+    range: 1.0,
+    damage: 20.0,
+    wind_up_micros: 600_000,
+    strike_micros: 200_000,
+    recovery_micros: 400_000,
+    cooldown_micros: 600_000,
 };
 
-use crate::tables::{player::Player, zombie::Zombie};
+/// Distance between the followers and their pack leader.
+const PACK_FOLLOW_SPACING: f32 = 2.0;
+/// Followers only repath once their slot moved this far, the leader does the pathfinding.
+const PACK_REPATH_DISTANCE: f32 = 2.0;
+/// Followers this close to the pack target break formation to attack it.
+const PACK_ENGAGE_RANGE: f32 = 5.0;
+
+/// Zombies give up investigating a noise after this long.
+const INVESTIGATE_MICROS: i64 = 8_000_000;
+const INVESTIGATE_TIMER: &str = "investigate";
+/// Last destination requested while following a pack leader.
+const FOLLOW_DESTINATION: &str = "follow_destination";
+
+impl MeleeEnemy for Zombie {
+    const MELEE_ATTACK: MeleeAttackProfile = ZOMBIE_MELEE_ATTACK;
+
+    fn melee_attack_mut(&mut self) -> &mut MeleeAttack {
+        &mut self.attack
+    }
 
-#[derive(Serialize, Deserialize)]
+    fn set_attacking(&mut self, is_attacking: bool) {
+        self.is_attacking = is_attacking;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ZombieAction {
-    IsMoving,
-    TargetRandomPlayer,
+    HearNoise,
+    Investigate,
+    IsInvestigating,
+    IsPackFollower,
+    FollowLeader,
     Chase,
-    IsTargetReached,
-    Attack,
+    Enemy(EnemyAction),
+    Melee(MeleeAction),
 }
 
 impl BehaviorExecutor<ZombieAction> for Zombie {
@@ -29,80 +73,132 @@ impl BehaviorExecutor<ZombieAction> for Zombie {
         _dt: f32,
         action: &ZombieAction,
     ) -> Status {
+        let status = self.execute_action(ctx, action);
+        trace_action(ctx, ZOMBIE_ARCHETYPE_ID, self.id, action, &status);
+        status
+    }
+}
+
+impl Zombie {
+    fn execute_action(&mut self, ctx: &ReducerContext, action: &ZombieAction) -> Status {
         let mut agent = NavigationAgent::find(ctx, self.navigation_agent_id)
             .expect("NavigationAgent not found");
         match action {
-            ZombieAction::IsMoving => {
-                if agent.is_moving() {
-                    Status::Success
-                } else {
-                    Status::Failure
-                }
-            }
-            ZombieAction::IsTargetReached => {
-                if agent.has_reached_destination() {
-                    Status::Success
-                } else {
-                    Status::Failure
+            ZombieAction::HearNoise => {
+                let Some(noise) = NoiseEvent::loudest_heard(ctx, agent.position()) else {
+                    return Status::Failure;
+                };
+
+                // Ignore the noises made by our target or the one we are already investigating
+                let is_known = match noise.source_player {
+                    Some(player_id) => self.target_player == Some(player_id),
+                    None => self
+                        .blackboard
+                        .last_heard_noise
+                        .is_some_and(|position| position.distance(&noise.position) < 1.0),
+                };
+                if is_known {
+                    return Status::Failure;
                 }
-            }
-            ZombieAction::TargetRandomPlayer => {
-                let players = Player::as_vec(ctx);
-                if players.is_empty() {
+
+                // A player gave away their position, go after them through the chase branches
+                if let Some(player_id) = noise.source_player {
+                    self.set_target(player_id);
+                    self.clone().update(ctx);
                     return Status::Failure;
                 }
 
-                let target = players.choose(&mut ctx.rng()).unwrap();
+                self.blackboard.last_heard_noise = Some(noise.position);
+                self.clone().update(ctx);
+                Status::Success
+            }
+            ZombieAction::Investigate => {
+                let Some(position) = self.blackboard.last_heard_noise else {
+                    return Status::Failure;
+                };
 
-                agent
-                    .set_destination(Some(target.position))
-                    .set_paused(false);
+                agent.set_destination(Some(position)).set_paused(false);
                 agent.update(ctx);
 
-                self.target_player = Some(target.id);
-                self.is_attacking = false;
+                self.blackboard.clear_position(FOLLOW_DESTINATION);
+                self.blackboard.set_timer(
+                    INVESTIGATE_TIMER,
+                    ctx.timestamp + TimeDuration::from_micros(INVESTIGATE_MICROS),
+                );
                 self.clone().update(ctx);
                 Status::Success
             }
-            ZombieAction::Chase => {
-                if let Some(player) = Player::find(ctx, self.target_player.unwrap()) {
-                    agent
-                        .set_destination(Some(player.position))
-                        .set_paused(false);
-                    agent.update(ctx);
-                    return Status::Success;
+            ZombieAction::IsInvestigating => {
+                match !self.blackboard.timer_elapsed(ctx, INVESTIGATE_TIMER)
+                    && !agent.has_reached_destination()
+                {
+                    true => Status::Success,
+                    false => Status::Failure,
                 }
-
-                // Target player not found, fail the action to re-target
-                Status::Failure
             }
-            ZombieAction::Attack => {
-                let player = Player::find(ctx, self.target_player.unwrap());
-
-                if player.is_none() {
-                    return Status::Failure;
+            ZombieAction::IsPackFollower => {
+                match self
+                    .pack_id
+                    .and_then(|pack_id| ZombiePack::find(ctx, pack_id))
+                    .is_some_and(|pack| pack.leader_id != self.id)
+                {
+                    true => Status::Success,
+                    false => Status::Failure,
                 }
+            }
+            ZombieAction::FollowLeader => {
+                let Some(pack) = self
+                    .pack_id
+                    .and_then(|pack_id| ZombiePack::find(ctx, pack_id))
+                else {
+                    return Status::Failure;
+                };
+                let Some(leader) = Zombie::find(ctx, pack.leader_id) else {
+                    return Status::Failure;
+                };
+                // The pack has no target yet, fail to pick one
+                let Some(player) = pack.target_player.and_then(|id| Player::find(ctx, id)) else {
+                    return Status::Failure;
+                };
 
-                if ctx.timestamp <= self.next_attack_time {
-                    self.is_attacking = false;
+                self.target_player = Some(player.id);
+                if player.position.distance(&agent.position()) <= PACK_ENGAGE_RANGE {
+                    self.blackboard.clear_position(FOLLOW_DESTINATION);
                     self.clone().update(ctx);
-                    return Status::Success;
+                    return Status::Failure;
                 }
 
-                let player = player.unwrap();
+                // Followers are spread evenly around the leader, ordered by id
+                let mut followers: Vec<u64> = pack
+                    .members(ctx)
+                    .map(|zombie| zombie.id)
+                    .filter(|id| *id != leader.id)
+                    .collect();
+                followers.sort();
+                let slot = followers.iter().position(|id| *id == self.id).unwrap_or(0);
+                let angle = std::f32::consts::TAU * slot as f32 / followers.len().max(1) as f32;
+                let destination = point_at_angle(leader.position, angle, PACK_FOLLOW_SPACING);
 
-                if player.position.distance(&agent.position()) > 1.0 {
-                    self.is_attacking = false;
-                    self.clone().update(ctx);
-                    return Status::Failure;
+                if self
+                    .blackboard
+                    .position(FOLLOW_DESTINATION)
+                    .is_none_or(|previous| previous.distance(&destination) > PACK_REPATH_DISTANCE)
+                {
+                    agent.set_destination(Some(destination)).set_paused(false);
+                    agent.update(ctx);
+                    self.blackboard
+                        .set_position(FOLLOW_DESTINATION, destination);
                 }
 
-                self.is_attacking = true;
-                self.next_attack_time = ctx.timestamp + TimeDuration::from_micros(1_000_000);
                 self.clone().update(ctx);
-
                 Status::Success
             }
+            ZombieAction::Chase => {
+                self.blackboard.clear_position(FOLLOW_DESTINATION);
+                EnemyAction::Chase.run(ctx, self, agent)
+            }
+            ZombieAction::Enemy(action) => action.run(ctx, self, agent),
+            ZombieAction::Melee(action) => action.run(ctx, self, agent),
         }
     }
 }
@@ -126,29 +222,60 @@ impl ZombieUpdateTick {
 
 pub fn create_zombie_behavior_tree(ctx: &ReducerContext) {
     let bt = Select(vec![
+        // Tear down the barricade of our spawn point before getting in
+        Sequence(vec![
+            Action(ZombieAction::Enemy(EnemyAction::IsBehindBarricade)),
+            Action(ZombieAction::Enemy(EnemyAction::AttackObstacle)),
+        ]),
+        // Finish an attack once it started, even if the target moved away
+        Sequence(vec![
+            Action(ZombieAction::Melee(MeleeAction::IsAttacking)),
+            Action(ZombieAction::Melee(MeleeAction::Attack)),
+        ]),
+        // If we are stuck, repath, then side-step and finally teleport to the nearest spawn
+        Sequence(vec![
+            Action(ZombieAction::Enemy(EnemyAction::IsStuck)),
+            Select(vec![
+                Action(ZombieAction::Enemy(EnemyAction::Repath)),
+                Action(ZombieAction::Enemy(EnemyAction::SideStep)),
+                Action(ZombieAction::Enemy(EnemyAction::TeleportToSpawn)),
+            ]),
+        ]),
+        // Let a side-step play out before chasing again
+        Action(ZombieAction::Enemy(EnemyAction::IsRecovering)),
+        // Let a knock back push us before chasing again
+        Action(ZombieAction::Enemy(EnemyAction::IsKnockedBack)),
+        // Walk to the loudest noise we hear, then keep going until we get there
+        Sequence(vec![
+            Action(ZombieAction::HearNoise),
+            Action(ZombieAction::Investigate),
+        ]),
+        Action(ZombieAction::IsInvestigating),
+        // Pack followers stay around their leader until close enough to the pack target
+        Sequence(vec![
+            Action(ZombieAction::IsPackFollower),
+            Action(ZombieAction::FollowLeader),
+        ]),
         // If we are moving, chase the target (i.e update target position)
         Sequence(vec![
-            Action(ZombieAction::IsMoving),
+            Action(ZombieAction::Enemy(EnemyAction::IsMoving)),
             Action(ZombieAction::Chase),
         ]),
         // If we reached the target, attack
         Sequence(vec![
-            Action(ZombieAction::IsTargetReached),
-            Action(ZombieAction::Attack),
+            Action(ZombieAction::Enemy(EnemyAction::IsTargetReached)),
+            Action(ZombieAction::Melee(MeleeAction::Attack)),
         ]),
-        // Otherwise, target a random player
-        Action(ZombieAction::TargetRandomPlayer),
+        // Otherwise wait for a free slot around the target, or pick one if we have none
+        Action(ZombieAction::Chase),
+        Action(ZombieAction::Enemy(EnemyAction::TargetRandomPlayer)),
     ]);
-    BehaviorTree::create(ctx, bt);
+    let leaves = tree_leaves(&bt);
+    let tree_id = BehaviorTree::create(ctx, bt).id;
+    ArchetypeBehaviorTree::register::<ZombieAction>(ctx, ZOMBIE_ARCHETYPE_ID, tree_id, leaves);
 }
 
 #[reducer]
-pub fn tick_zombie(ctx: &ReducerContext, tick: ZombieUpdateTick) {
-    let delta_time = match tick.scheduled_at {
-        ScheduleAt::Interval(dt) => dt.to_micros() as f32 / 1_000_000.0,
-        _ => 0.0,
-    };
-    let world = World::find(ctx, WORLD_ID).expect("World not found");
-    let mut zombies = Zombie::as_vec(ctx);
-    tick_behavior(ctx, &world, 1, delta_time, &mut zombies);
+pub fn tick_zombie(ctx: &ReducerContext, tick: ZombieUpdateTick) -> Result<(), String> {
+    tick_enemies::<Zombie, ZombieAction>(ctx, tick.scheduled_at)
 }
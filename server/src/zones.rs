@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use spacetime_engine::{
+    collisions::{ColliderId, Trigger, TriggerId},
+    math::Vec3,
+    utils::{get_delta_time, Entity, WorldEntity},
+};
+use spacetimedb::{
+    reducer, table, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+};
+
+use crate::{
+    archetypes::ArchetypeId,
+    constants::{POWER_UP_COLLIDER_ID, SPITTER_AOE_COLLIDER_ID},
+    power_ups::PowerUpKind,
+    tables::{
+        entity_rigid_body::{EntityKind, EntityRigidBody},
+        player::Player,
+    },
+};
+
+/// Damage per second taken while standing in spitter acid.
+const SPITTER_ACID_DAMAGE_PER_SECOND: f32 = 10.0;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZoneKind {
+    /// Acid pool left by spitter projectiles, hurting the players standing in it.
+    SpitterAcid,
+    /// Power-up picked up by the first player walking into it.
+    PowerUp(PowerUpKind),
+}
+
+impl ZoneKind {
+    fn collider_id(self) -> ColliderId {
+        match self {
+            ZoneKind::SpitterAcid => SPITTER_AOE_COLLIDER_ID,
+            ZoneKind::PowerUp(_) => POWER_UP_COLLIDER_ID,
+        }
+    }
+
+    /// Time before the zone is removed, if nothing consumed it first.
+    fn lifetime_micros(self) -> i64 {
+        match self {
+            ZoneKind::SpitterAcid => 3_000_000,
+            ZoneKind::PowerUp(_) => 30_000_000,
+        }
+    }
+}
+
+/// Game entity owning a rigid body found in a zone's trigger.
+pub enum ZoneOccupant {
+    Player(Player),
+    Enemy { archetype: ArchetypeId, id: u64 },
+}
+
+impl ZoneOccupant {
+    pub fn find(ctx: &ReducerContext, rigid_body_id: u64) -> Option<Self> {
+        let entity = EntityRigidBody::find(ctx, rigid_body_id)?;
+        match entity.kind {
+            EntityKind::Player => Player::find(ctx, entity.entity_id).map(ZoneOccupant::Player),
+            EntityKind::Enemy(archetype) => Some(ZoneOccupant::Enemy {
+                archetype,
+                id: entity.entity_id,
+            }),
+        }
+    }
+}
+
+/// Area of the world reacting to the entities entering, staying in and leaving its trigger.
+#[table(name = zone, public, scheduled(tick_zone))]
+pub struct Zone {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub kind: ZoneKind,
+    pub trigger_id: TriggerId,
+    pub position: Vec3,
+    pub expires_at: Timestamp,
+    pub scheduled_at: ScheduleAt,
+}
+
+impl Entity for Zone {
+    fn insert(self, ctx: &ReducerContext) -> Self {
+        ctx.db.zone().insert(self)
+    }
+
+    fn find(ctx: &ReducerContext, id: u64) -> Option<Self> {
+        ctx.db.zone().id().find(id)
+    }
+
+    fn iter(ctx: &ReducerContext) -> impl Iterator<Item = Self> {
+        ctx.db.zone().iter()
+    }
+
+    fn as_map(ctx: &ReducerContext) -> HashMap<u64, Self> {
+        ctx.db.zone().iter().map(|zone| (zone.id, zone)).collect()
+    }
+
+    fn as_vec(ctx: &ReducerContext) -> Vec<Self> {
+        ctx.db.zone().iter().collect()
+    }
+
+    fn update(self, ctx: &ReducerContext) -> Self {
+        ctx.db.zone().id().update(self)
+    }
+
+    fn delete(&self, ctx: &ReducerContext) {
+        if let Some(trigger) = Trigger::find(ctx, self.trigger_id) {
+            trigger.delete(ctx);
+        }
+        ctx.db.zone().id().delete(self.id);
+    }
+
+    fn clear(ctx: &ReducerContext) {
+        for zone in ctx.db.zone().iter() {
+            zone.delete(ctx);
+        }
+    }
+
+    fn count(ctx: &ReducerContext) -> u64 {
+        ctx.db.zone().count()
+    }
+}
+
+impl Zone {
+    pub fn create(ctx: &ReducerContext, kind: ZoneKind, position: Vec3) -> Self {
+        let trigger_id = Trigger::builder()
+            .position(position)
+            .collider_id(kind.collider_id())
+            .build()
+            .insert(ctx)
+            .id;
+        Zone {
+            id: 0,
+            kind,
+            trigger_id,
+            position,
+            expires_at: ctx.timestamp + TimeDuration::from_micros(kind.lifetime_micros()),
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(250_000)),
+        }
+        .insert(ctx)
+    }
+
+    fn on_enter(&self, _ctx: &ReducerContext, occupant: &ZoneOccupant) {
+        match occupant {
+            ZoneOccupant::Player(player) => {
+                log::debug!("Player#{} entered {:?}#{}", player.id, self.kind, self.id)
+            }
+            ZoneOccupant::Enemy { archetype, id } => log::debug!(
+                "Enemy#{} (archetype {}) entered {:?}#{}",
+                id,
+                archetype,
+                self.kind,
+                self.id
+            ),
+        }
+    }
+
+    fn on_exit(&self, _ctx: &ReducerContext, occupant: &ZoneOccupant) {
+        match occupant {
+            ZoneOccupant::Player(player) => {
+                log::debug!("Player#{} left {:?}#{}", player.id, self.kind, self.id)
+            }
+            ZoneOccupant::Enemy { archetype, id } => log::debug!(
+                "Enemy#{} (archetype {}) left {:?}#{}",
+                id,
+                archetype,
+                self.kind,
+                self.id
+            ),
+        }
+    }
+
+    /// Called on every tick for each occupant inside the zone. Returns true if the
+    /// zone was consumed and must be removed.
+    fn on_stay(&self, ctx: &ReducerContext, occupant: ZoneOccupant, delta_time: f32) -> bool {
+        match (self.kind, occupant) {
+            (ZoneKind::SpitterAcid, ZoneOccupant::Player(player)) => {
+                player.damage(ctx, SPITTER_ACID_DAMAGE_PER_SECOND * delta_time);
+                false
+            }
+            // Picked up while inside rather than on enter, so a pickup is never
+            // missed if the enter event happened between two zone ticks
+            (ZoneKind::PowerUp(kind), ZoneOccupant::Player(player)) => {
+                kind.apply(ctx, player);
+                true
+            }
+            (_, ZoneOccupant::Enemy { .. }) => false,
+        }
+    }
+}
+
+#[reducer]
+fn tick_zone(ctx: &ReducerContext, tick: Zone) {
+    let Some(trigger) = Trigger::find(ctx, tick.trigger_id) else {
+        tick.delete(ctx);
+        return;
+    };
+
+    if ctx.timestamp >= tick.expires_at {
+        tick.delete(ctx);
+        return;
+    }
+
+    for rigid_body_id in &trigger.added_entities {
+        if let Some(occupant) = ZoneOccupant::find(ctx, *rigid_body_id) {
+            tick.on_enter(ctx, &occupant);
+        }
+    }
+    for rigid_body_id in &trigger.removed_entities {
+        if let Some(occupant) = ZoneOccupant::find(ctx, *rigid_body_id) {
+            tick.on_exit(ctx, &occupant);
+        }
+    }
+
+    let delta_time = get_delta_time(tick.scheduled_at);
+    for rigid_body_id in &trigger.entities_inside {
+        let Some(occupant) = ZoneOccupant::find(ctx, *rigid_body_id) else {
+            continue;
+        };
+        if tick.on_stay(ctx, occupant, delta_time) {
+            tick.delete(ctx);
+            return;
+        }
+    }
+}